@@ -8,6 +8,7 @@ use seeder::{
     error::Error,
     rpc::{external, internal},
     state::AppState,
+    task::{health_monitor, lease_sweeper, reconciliation},
     types::*,
 };
 use serde::{Deserialize, Serialize};
@@ -61,15 +62,20 @@ async fn main() -> Result<(), Error> {
             // Initialize the database.
             KvStore::open(config.database_path())?.init();
 
-            let app_state: AppState = AppState::new(
-                config.clone(),
-                CachedKvStore::default(),
-                CachedKvStore::default(),
-            );
+            let app_state: AppState = AppState::new(config.clone(), CachedKvStore::default());
 
             initialize_clients(&app_state).await?;
             tracing::info!("Successfully initialized app state.");
 
+            // Start probing the registered tx_orderers.
+            health_monitor::initialize(app_state.clone());
+
+            // Start reconciling the registry with the liveness contracts.
+            reconciliation::initialize(app_state.clone());
+
+            // Start expiring the registrations that are not renewed.
+            lease_sweeper::initialize(app_state.clone());
+
             // Initialize the internal rpc server.
             initialize_internal_rpc_server(&app_state).await?;
 
@@ -87,19 +93,12 @@ async fn initialize_clients(app_state: &AppState) -> Result<(), Error> {
 
     for (platform, service_provider) in liveness_info_list.iter() {
         let liveness_info_payload = LivenessInfoPayload::get(*platform, *service_provider)?;
-        match liveness_info_payload {
-            LivenessInfoPayload::Ethereum(liveness_info) => {
-                liveness::radius::LivenessClient::initialize(
-                    app_state.clone(),
-                    *platform,
-                    *service_provider,
-                    liveness_info,
-                );
-            }
-            LivenessInfoPayload::Local(_payload) => {
-                todo!("Implement 'LivenessClient' for local ordering.");
-            }
-        }
+        liveness::initialize(
+            app_state.clone(),
+            *platform,
+            *service_provider,
+            liveness_info_payload,
+        );
     }
 
     Ok(())
@@ -113,6 +112,13 @@ async fn initialize_internal_rpc_server(context: &AppState) -> Result<(), Error>
         .register_rpc_method::<internal::AddLivenessInfo>()?
         .register_rpc_method::<internal::GetLivenessInfo>()?
         .register_rpc_method::<internal::GetLivenessInfos>()?
+        .register_rpc_method::<internal::GetReconciliationReport>()?
+        .register_rpc_method::<internal::GetTxOrdererHistory>()?
+        .register_rpc_method::<internal::MigrateLegacyTxOrderers>()?
+        .register_rpc_method::<internal::RemoveLivenessInfo>()?
+        .register_rpc_method::<internal::RemoveTxOrderer>()?
+        .register_rpc_method::<internal::SetExecutorAddressList>()?
+        .register_rpc_method::<internal::UpdateLivenessInfo>()?
         .init(internal_rpc_url.clone())
         .await?;
 
@@ -133,11 +139,18 @@ async fn initialize_external_rpc_server(context: &AppState) -> Result<JoinHandle
 
     // Initialize the seeder internal RPC server.
     let internal_rpc_server = RpcServer::new(context.clone())
+        .register_rpc_method::<external::DeregisterExecutor>()?
         .register_rpc_method::<external::DeregisterTxOrderer>()?
+        .register_rpc_method::<external::GetClusterTxOrdererRpcInfoList>()?
         .register_rpc_method::<external::GetExecutorRpcInfoList>()?
+        .register_rpc_method::<external::GetRegistryChanges>()?
+        .register_rpc_method::<external::GetSignatureDomain>()?
         .register_rpc_method::<external::GetTxOrdererRpcUrl>()?
         .register_rpc_method::<external::GetTxOrdererRpcInfoList>()?
+        .register_rpc_method::<external::RegisterExecutor>()?
         .register_rpc_method::<external::RegisterTxOrderer>()?
+        .register_rpc_method::<external::RenewTxOrderer>()?
+        .register_rpc_method::<external::UpdateTxOrdererRpcUrl>()?
         .init(external_rpc_url.clone())
         .await?;
 
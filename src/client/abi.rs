@@ -0,0 +1,68 @@
+//! Encoding of the few contract calls made through `eth_call`, whose ABI is
+//! not exposed by `Publisher`.
+
+use crate::{
+    client::storage_proof::{decode_hex, keccak256, Word},
+    error::Error,
+};
+
+/// Calldata for `signature`, e.g. "isOperator(address)", with every argument
+/// already encoded as a static word.
+pub fn encode_call(signature: &str, argument_list: &[Word]) -> Vec<u8> {
+    let mut calldata = keccak256(signature.as_bytes())[..4].to_vec();
+    for argument in argument_list {
+        calldata.extend_from_slice(argument);
+    }
+
+    calldata
+}
+
+pub fn encode_address(address: impl AsRef<str>) -> Result<Word, Error> {
+    let address = decode_hex(address.as_ref()).map_err(|_| Error::InvalidAbiAddress)?;
+    if address.len() != 20 {
+        return Err(Error::InvalidAbiAddress);
+    }
+
+    let mut word = Word::default();
+    word[12..].copy_from_slice(&address);
+
+    Ok(word)
+}
+
+/// `bytes32` made of an address followed by a `uint96` identifier, the way
+/// Symbiotic identifies a subnetwork.
+pub fn encode_subnetwork(network_address: impl AsRef<str>, identifier: u64) -> Result<Word, Error> {
+    let network_address = encode_address(network_address)?;
+
+    let mut word = Word::default();
+    word[..20].copy_from_slice(&network_address[12..]);
+    word[24..].copy_from_slice(&identifier.to_be_bytes());
+
+    Ok(word)
+}
+
+fn decode_word(output: &[u8]) -> Result<Word, Error> {
+    output
+        .get(..32)
+        .and_then(|word| word.try_into().ok())
+        .ok_or(Error::InvalidAbiOutput)
+}
+
+pub fn decode_bool(output: &[u8]) -> Result<bool, Error> {
+    Ok(decode_word(output)?[31] != 0)
+}
+
+pub fn decode_u8(output: &[u8]) -> Result<u8, Error> {
+    Ok(decode_word(output)?[31])
+}
+
+/// A `uint256` clamped to `u128`, which is more than enough to compare stake
+/// amounts against a threshold.
+pub fn decode_u128_saturating(output: &[u8]) -> Result<u128, Error> {
+    let word = decode_word(output)?;
+    if word[..16].iter().any(|byte| *byte != 0) {
+        return Ok(u128::MAX);
+    }
+
+    Ok(u128::from_be_bytes(word[16..].try_into().unwrap()))
+}
@@ -0,0 +1,311 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use crate::{
+    client::storage_proof::{self, AccountProof},
+    error::Error,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimal Ethereum JSON-RPC client for the calls `Publisher` does not expose.
+#[derive(Clone)]
+pub struct EthereumRpcClient {
+    rpc_url: String,
+    http_client: Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub number: String,
+    pub hash: String,
+    pub parent_hash: String,
+    pub state_root: String,
+    // The remaining fields are only needed to recompute the hash.
+    #[serde(default)]
+    pub sha3_uncles: String,
+    #[serde(default)]
+    pub miner: String,
+    #[serde(default)]
+    pub transactions_root: String,
+    #[serde(default)]
+    pub receipts_root: String,
+    #[serde(default)]
+    pub logs_bloom: String,
+    #[serde(default)]
+    pub difficulty: String,
+    #[serde(default)]
+    pub gas_limit: String,
+    #[serde(default)]
+    pub gas_used: String,
+    #[serde(default)]
+    pub timestamp: String,
+    #[serde(default)]
+    pub extra_data: String,
+    #[serde(default)]
+    pub mix_hash: String,
+    #[serde(default)]
+    pub nonce: String,
+    pub base_fee_per_gas: Option<String>,
+    pub withdrawals_root: Option<String>,
+    pub blob_gas_used: Option<String>,
+    pub excess_blob_gas: Option<String>,
+    pub parent_beacon_block_root: Option<String>,
+    pub requests_hash: Option<String>,
+}
+
+impl BlockHeader {
+    pub fn block_number(&self) -> Result<u64, Error> {
+        parse_quantity(&self.number)
+    }
+
+    /// Check that the hash of the header is the hash of its fields, so that a
+    /// header with a forged state root cannot pass for the block.
+    pub fn verify_hash(&self) -> Result<(), Error> {
+        let hash = storage_proof::decode_hex(&self.hash)?;
+        if storage_proof::keccak256(&self.encode()?).as_slice() != hash.as_slice() {
+            return Err(Error::InvalidBlockHeader("block hash mismatch"));
+        }
+
+        Ok(())
+    }
+
+    /// RLP encoding of the header. Fields added by later forks are only
+    /// present in the blocks produced after them.
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let bytes = |hex: &str| storage_proof::decode_hex(hex);
+        let quantity = |hex: &str| -> Result<Vec<u8>, Error> {
+            let bytes = storage_proof::decode_hex(hex)?;
+
+            Ok(bytes.into_iter().skip_while(|byte| *byte == 0).collect())
+        };
+
+        let mut item_list = vec![
+            bytes(&self.parent_hash)?,
+            bytes(&self.sha3_uncles)?,
+            bytes(&self.miner)?,
+            bytes(&self.state_root)?,
+            bytes(&self.transactions_root)?,
+            bytes(&self.receipts_root)?,
+            bytes(&self.logs_bloom)?,
+            quantity(&self.difficulty)?,
+            quantity(&self.number)?,
+            quantity(&self.gas_limit)?,
+            quantity(&self.gas_used)?,
+            quantity(&self.timestamp)?,
+            bytes(&self.extra_data)?,
+            bytes(&self.mix_hash)?,
+            bytes(&self.nonce)?,
+        ];
+        if let Some(base_fee_per_gas) = &self.base_fee_per_gas {
+            item_list.push(quantity(base_fee_per_gas)?);
+        }
+        if let Some(withdrawals_root) = &self.withdrawals_root {
+            item_list.push(bytes(withdrawals_root)?);
+        }
+        if let Some(blob_gas_used) = &self.blob_gas_used {
+            item_list.push(quantity(blob_gas_used)?);
+        }
+        if let Some(excess_blob_gas) = &self.excess_blob_gas {
+            item_list.push(quantity(excess_blob_gas)?);
+        }
+        if let Some(parent_beacon_block_root) = &self.parent_beacon_block_root {
+            item_list.push(bytes(parent_beacon_block_root)?);
+        }
+        if let Some(requests_hash) = &self.requests_hash {
+            item_list.push(bytes(requests_hash)?);
+        }
+
+        Ok(storage_proof::encode_rlp_bytes_list(&item_list))
+    }
+}
+
+impl EthereumRpcClient {
+    pub fn new(rpc_url: impl AsRef<str>) -> Result<Self, Error> {
+        let http_client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(Error::InvalidURL)?;
+
+        Ok(Self {
+            rpc_url: rpc_url.as_ref().to_owned(),
+            http_client,
+        })
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    pub async fn request<T>(&self, method: &'static str, params: Value) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let response: JsonRpcResponse<T> = self
+            .http_client
+            .post(&self.rpc_url)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+                "id": 1,
+            }))
+            .send()
+            .await
+            .map_err(Error::EthereumRpc)?
+            .json()
+            .await
+            .map_err(Error::EthereumRpc)?;
+
+        match (response.result, response.error) {
+            (Some(result), None) => Ok(result),
+            (_, error) => Err(Error::EthereumRpcResponse(
+                method,
+                error.unwrap_or(Value::Null),
+            )),
+        }
+    }
+
+    pub async fn block_number(&self) -> Result<u64, Error> {
+        let block_number: String = self.request("eth_blockNumber", json!([])).await?;
+
+        parse_quantity(&block_number)
+    }
+
+    pub async fn chain_id(&self) -> Result<u64, Error> {
+        let chain_id: String = self.request("eth_chainId", json!([])).await?;
+
+        parse_quantity(&chain_id)
+    }
+
+    pub async fn block_header(&self, block_number: u64) -> Result<BlockHeader, Error> {
+        self.request(
+            "eth_getBlockByNumber",
+            json!([format!("{:#x}", block_number), false]),
+        )
+        .await
+    }
+
+    pub async fn call(&self, to: &str, data: &[u8], block_number: u64) -> Result<Vec<u8>, Error> {
+        let output: String = self
+            .request(
+                "eth_call",
+                json!([
+                    {
+                        "to": to,
+                        "data": format!("0x{}", storage_proof::encode_hex(data)),
+                    },
+                    format!("{:#x}", block_number),
+                ]),
+            )
+            .await?;
+
+        storage_proof::decode_hex(&output)
+            .map_err(|_| Error::EthereumRpcResponse("eth_call", Value::from(output)))
+    }
+
+    /// Logs emitted by the contract in the inclusive block range.
+    pub async fn logs(
+        &self,
+        address: &str,
+        from_block_number: u64,
+        to_block_number: u64,
+    ) -> Result<Vec<Value>, Error> {
+        self.request(
+            "eth_getLogs",
+            json!([{
+                "address": address,
+                "fromBlock": format!("{:#x}", from_block_number),
+                "toBlock": format!("{:#x}", to_block_number),
+            }]),
+        )
+        .await
+    }
+
+    pub async fn get_proof(
+        &self,
+        address: &str,
+        storage_keys: &[String],
+        block_number: u64,
+    ) -> Result<AccountProof, Error> {
+        self.request(
+            "eth_getProof",
+            json!([address, storage_keys, format!("{:#x}", block_number)]),
+        )
+        .await
+    }
+}
+
+/// Parse a hex-encoded JSON-RPC quantity such as "0x1b4".
+pub fn parse_quantity(quantity: &str) -> Result<u64, Error> {
+    u64::from_str_radix(quantity.trim_start_matches("0x"), 16)
+        .map_err(|_| Error::EthereumRpcResponse("parse_quantity", Value::from(quantity)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `eth_getBlockByNumber` response for the genesis block of Ethereum
+    /// mainnet.
+    fn genesis_block_header() -> BlockHeader {
+        serde_json::from_value(json!({
+            "number": "0x0",
+            "hash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+            "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+            "miner": "0x0000000000000000000000000000000000000000",
+            "stateRoot": "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544",
+            "transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+            "receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "difficulty": "0x400000000",
+            "gasLimit": "0x1388",
+            "gasUsed": "0x0",
+            "timestamp": "0x0",
+            "extraData": "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "nonce": "0x0000000000000042",
+            "totalDifficulty": "0x400000000",
+            "size": "0x21c",
+            "transactions": [],
+            "uncles": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_block_header_hash() {
+        let block_header = genesis_block_header();
+
+        assert!(block_header.verify_hash().is_ok());
+        assert_eq!(block_header.block_number().unwrap(), 0);
+    }
+
+    #[test]
+    fn reject_block_header_with_forged_state_root() {
+        let mut block_header = genesis_block_header();
+        block_header.state_root =
+            "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0545".to_owned();
+
+        assert!(block_header.verify_hash().is_err());
+    }
+
+    #[test]
+    fn reject_block_header_with_missing_field() {
+        let mut block_header = genesis_block_header();
+        block_header.base_fee_per_gas = Some("0x7".to_owned());
+
+        assert!(block_header.verify_hash().is_err());
+    }
+}
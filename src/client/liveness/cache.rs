@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use super::ClusterMembership;
+use crate::types::QueryCacheStats;
+
+/// The block margin only changes through a contract upgrade, so it is kept
+/// for many blocks.
+const BLOCK_MARGIN_TTL_BLOCKS: u32 = 300;
+
+/// Cluster membership at a given block only changes on a reorg, which clears
+/// the cache. Entries expire after a while to bound the memory used by blocks
+/// that are no longer queried.
+const TX_ORDERER_LIST_TTL_BLOCKS: u32 = 64;
+
+struct CacheEntry<T> {
+    value: T,
+    expires_at: Instant,
+}
+
+impl<T> CacheEntry<T> {
+    fn new(value: T, ttl: Duration) -> Self {
+        Self {
+            value,
+            expires_at: Instant::now() + ttl,
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        Instant::now() < self.expires_at
+    }
+}
+
+/// Cache of liveness contract queries with TTLs tied to the block time.
+pub struct QueryCache {
+    block_time: Duration,
+    block_number: Mutex<Option<CacheEntry<u64>>>,
+    block_margin: Mutex<Option<CacheEntry<u64>>>,
+    tx_orderer_list: Mutex<HashMap<(String, u64), CacheEntry<ClusterMembership>>>,
+    hit_count: AtomicU64,
+    miss_count: AtomicU64,
+}
+
+impl QueryCache {
+    pub fn new(block_time: Duration) -> Self {
+        Self {
+            block_time,
+            block_number: Mutex::new(None),
+            block_margin: Mutex::new(None),
+            tx_orderer_list: Mutex::new(HashMap::new()),
+            hit_count: AtomicU64::new(0),
+            miss_count: AtomicU64::new(0),
+        }
+    }
+
+    pub fn block_number(&self) -> Option<u64> {
+        let block_number = self
+            .block_number
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|entry| entry.is_fresh())
+            .map(|entry| entry.value);
+
+        self.count(block_number)
+    }
+
+    pub fn set_block_number(&self, block_number: u64) {
+        *self.block_number.lock().unwrap() = Some(CacheEntry::new(block_number, self.block_time));
+    }
+
+    pub fn block_margin(&self) -> Option<u64> {
+        let block_margin = self
+            .block_margin
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|entry| entry.is_fresh())
+            .map(|entry| entry.value);
+
+        self.count(block_margin)
+    }
+
+    pub fn set_block_margin(&self, block_margin: u64) {
+        *self.block_margin.lock().unwrap() = Some(CacheEntry::new(
+            block_margin,
+            self.block_time * BLOCK_MARGIN_TTL_BLOCKS,
+        ));
+    }
+
+    pub fn tx_orderer_list(
+        &self,
+        cluster_id: &str,
+        block_number: u64,
+    ) -> Option<ClusterMembership> {
+        let tx_orderer_list = self
+            .tx_orderer_list
+            .lock()
+            .unwrap()
+            .get(&(cluster_id.to_owned(), block_number))
+            .filter(|entry| entry.is_fresh())
+            .map(|entry| entry.value.clone());
+
+        self.count(tx_orderer_list)
+    }
+
+    pub fn set_tx_orderer_list(
+        &self,
+        cluster_id: &str,
+        block_number: u64,
+        tx_orderer_list: ClusterMembership,
+    ) {
+        let mut cache = self.tx_orderer_list.lock().unwrap();
+        cache.retain(|_, entry| entry.is_fresh());
+        cache.insert(
+            (cluster_id.to_owned(), block_number),
+            CacheEntry::new(
+                tx_orderer_list,
+                self.block_time * TX_ORDERER_LIST_TTL_BLOCKS,
+            ),
+        );
+    }
+
+    /// Called on every new block, after which the cached latest block is
+    /// stale.
+    pub fn invalidate_block_number(&self) {
+        *self.block_number.lock().unwrap() = None;
+    }
+
+    /// Called when blocks are reorganized, which can change the cluster
+    /// membership at any cached block.
+    pub fn invalidate(&self) {
+        *self.block_number.lock().unwrap() = None;
+        *self.block_margin.lock().unwrap() = None;
+        self.tx_orderer_list.lock().unwrap().clear();
+    }
+
+    pub fn stats(&self) -> QueryCacheStats {
+        QueryCacheStats {
+            hit_count: self.hit_count.load(Ordering::Relaxed),
+            miss_count: self.miss_count.load(Ordering::Relaxed),
+            tx_orderer_list_count: self.tx_orderer_list.lock().unwrap().len(),
+        }
+    }
+
+    fn count<T>(&self, value: Option<T>) -> Option<T> {
+        match value {
+            Some(_) => self.hit_count.fetch_add(1, Ordering::Relaxed),
+            None => self.miss_count.fetch_add(1, Ordering::Relaxed),
+        };
+
+        value
+    }
+}
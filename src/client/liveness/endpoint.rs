@@ -0,0 +1,509 @@
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use futures::future::{join_all, BoxFuture};
+
+use crate::{error::Error, types::EndpointHealth, util::current_timestamp};
+
+/// Upper bound of a single request before the endpoint is considered down.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Verifier<T> =
+    Box<dyn for<'a> Fn(&'a Endpoint<T>) -> BoxFuture<'a, Result<(), Error>> + Send + Sync>;
+
+pub struct Endpoint<T> {
+    url: String,
+    client: T,
+    health: Mutex<EndpointHealth>,
+    is_verified: AtomicBool,
+}
+
+impl<T> Endpoint<T> {
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn client(&self) -> &T {
+        &self.client
+    }
+
+    fn record_success(&self) {
+        let mut health = self.health.lock().unwrap();
+        health.success_count += 1;
+        health.consecutive_failures = 0;
+    }
+
+    fn record_disagreement(&self) {
+        self.health.lock().unwrap().disagreement_count += 1;
+    }
+
+    fn record_failure(&self, error: &Error) {
+        let mut health = self.health.lock().unwrap();
+        health.failure_count += 1;
+        health.consecutive_failures += 1;
+        health.last_error = Some(error.to_string());
+        health.last_failure_at = Some(current_timestamp());
+    }
+}
+
+/// A list of interchangeable endpoints. Requests go to the active endpoint
+/// and move on to the next one when it fails or times out.
+///
+/// With a verifier, an endpoint is only used once it has passed the
+/// verification. Endpoints that cannot be reached when the pool is created
+/// are verified on their first use instead.
+pub struct EndpointPool<T> {
+    endpoints: Vec<Endpoint<T>>,
+    active_index: AtomicUsize,
+    verifier: Option<Verifier<T>>,
+}
+
+impl<T> EndpointPool<T> {
+    pub fn new(endpoints: Vec<(String, T)>) -> Result<Self, Error> {
+        if endpoints.is_empty() {
+            return Err(Error::EmptyEndpointList);
+        }
+
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(url, client)| Endpoint {
+                health: Mutex::new(EndpointHealth {
+                    url: url.clone(),
+                    ..Default::default()
+                }),
+                url,
+                client,
+                is_verified: AtomicBool::new(false),
+            })
+            .collect();
+
+        Ok(Self {
+            endpoints,
+            active_index: AtomicUsize::new(0),
+            verifier: None,
+        })
+    }
+
+    /// Require every endpoint to pass `verifier` before it is used.
+    pub fn with_verifier<F>(mut self, verifier: F) -> Self
+    where
+        F: for<'a> Fn(&'a Endpoint<T>) -> BoxFuture<'a, Result<(), Error>> + Send + Sync + 'static,
+    {
+        self.verifier = Some(Box::new(verifier));
+        self
+    }
+
+    /// Verify every endpoint that has not passed the verification yet, and
+    /// return the outcome in the order of the endpoints.
+    pub async fn verify_all(&self) -> Vec<Result<(), Error>> {
+        join_all(self.endpoints.iter().map(|endpoint| async move {
+            match tokio::time::timeout(REQUEST_TIMEOUT, self.verify(endpoint)).await {
+                Ok(result) => result,
+                Err(_) => Err(Error::EndpointTimeout(endpoint.url.clone())),
+            }
+        }))
+        .await
+    }
+
+    async fn verify(&self, endpoint: &Endpoint<T>) -> Result<(), Error> {
+        let Some(verifier) = &self.verifier else {
+            return Ok(());
+        };
+        if endpoint.is_verified.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        verifier(endpoint).await?;
+        endpoint.is_verified.store(true, Ordering::Relaxed);
+        tracing::info!("Liveness endpoint {} verified", endpoint.url);
+
+        Ok(())
+    }
+
+    pub fn active(&self) -> &Endpoint<T> {
+        &self.endpoints[self.active_index.load(Ordering::Relaxed) % self.endpoints.len()]
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Endpoint<T>> {
+        self.endpoints.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// Record the outcome of a request made outside of `request`, rotating
+    /// away from the active endpoint on failure.
+    pub fn report(&self, result: Result<(), &Error>) {
+        let index = self.active_index.load(Ordering::Relaxed) % self.endpoints.len();
+
+        match result {
+            Ok(()) => self.endpoints[index].record_success(),
+            Err(error) => {
+                self.endpoints[index].record_failure(error);
+                self.rotate(index);
+            }
+        }
+    }
+
+    /// Send the request to the active endpoint, failing over to the others in
+    /// turn until one of them answers.
+    pub async fn request<'a, R, F, Fut>(&'a self, request: F) -> Result<R, Error>
+    where
+        F: Fn(&'a T) -> Fut,
+        Fut: Future<Output = Result<R, Error>> + 'a,
+    {
+        let mut last_error = Error::EmptyEndpointList;
+
+        for _ in 0..self.endpoints.len() {
+            let index = self.active_index.load(Ordering::Relaxed) % self.endpoints.len();
+            let endpoint = &self.endpoints[index];
+
+            let response = async {
+                self.verify(endpoint).await?;
+                request(&endpoint.client).await
+            };
+
+            let error = match tokio::time::timeout(REQUEST_TIMEOUT, response).await {
+                Ok(Ok(response)) => {
+                    endpoint.record_success();
+                    return Ok(response);
+                }
+                Ok(Err(error)) => error,
+                Err(_) => Error::EndpointTimeout(endpoint.url.clone()),
+            };
+
+            tracing::warn!("Liveness endpoint {} failed: {:?}", endpoint.url, error);
+            endpoint.record_failure(&error);
+            self.rotate(index);
+            last_error = error;
+        }
+
+        Err(last_error)
+    }
+
+    /// Send the request to every endpoint at once and return the answers in
+    /// the order of the endpoints.
+    pub async fn request_all<'a, R, F, Fut>(&'a self, request: F) -> Vec<Result<R, Error>>
+    where
+        F: Fn(&'a T) -> Fut,
+        Fut: Future<Output = Result<R, Error>> + 'a,
+    {
+        let request = &request;
+        let response_list = join_all(self.endpoints.iter().map(|endpoint| async move {
+            let response = async {
+                self.verify(endpoint).await?;
+                request(&endpoint.client).await
+            };
+
+            match tokio::time::timeout(REQUEST_TIMEOUT, response).await {
+                Ok(response) => response,
+                Err(_) => Err(Error::EndpointTimeout(endpoint.url.clone())),
+            }
+        }))
+        .await;
+
+        for (endpoint, response) in self.endpoints.iter().zip(response_list.iter()) {
+            match response {
+                Ok(_) => endpoint.record_success(),
+                Err(error) => {
+                    tracing::warn!("Liveness endpoint {} failed: {:?}", endpoint.url, error);
+                    endpoint.record_failure(error);
+                }
+            }
+        }
+
+        response_list
+    }
+
+    /// Send the request to every endpoint and accept the answer that at least
+    /// `quorum` of them agree on. Endpoints that answer differently are logged
+    /// and counted as disagreeing. Fails when more than one answer reaches the
+    /// quorum, which a quorum of more than half of the endpoints rules out.
+    pub async fn request_quorum<'a, R, F, Fut>(
+        &'a self,
+        quorum: usize,
+        request: F,
+    ) -> Result<R, Error>
+    where
+        R: PartialEq + Debug,
+        F: Fn(&'a T) -> Fut,
+        Fut: Future<Output = Result<R, Error>> + 'a,
+    {
+        let response_list = self.request_all(request).await;
+
+        // Group equal answers, remembering the first endpoint that gave each.
+        let mut answer_list: Vec<(usize, usize)> = Vec::new();
+        for (index, response) in response_list.iter().enumerate() {
+            let Ok(response) = response else {
+                continue;
+            };
+
+            match answer_list.iter_mut().find(|(first_index, _)| {
+                response_list[*first_index].as_ref().ok() == Some(response)
+            }) {
+                Some((_, count)) => *count += 1,
+                None => answer_list.push((index, 1)),
+            }
+        }
+
+        if answer_list
+            .iter()
+            .filter(|(_, count)| *count >= quorum)
+            .count()
+            > 1
+        {
+            tracing::warn!(
+                "Liveness endpoints reached a quorum of {} on different answers",
+                quorum
+            );
+            return Err(Error::ConflictingQuorum(quorum));
+        }
+
+        let (accepted_index, agreed) = answer_list
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .unwrap_or_default();
+        if agreed < quorum {
+            tracing::warn!(
+                "Liveness endpoints did not reach a quorum ({} of {} required)",
+                agreed,
+                quorum
+            );
+            return Err(Error::QuorumNotReached(agreed, quorum));
+        }
+
+        if let Ok(accepted) = &response_list[accepted_index] {
+            for (endpoint, response) in self.endpoints.iter().zip(response_list.iter()) {
+                if let Ok(response) = response {
+                    if response != accepted {
+                        tracing::warn!(
+                            "Liveness endpoint {} disagrees with the quorum: {:?} != {:?}",
+                            endpoint.url,
+                            response,
+                            accepted
+                        );
+                        endpoint.record_disagreement();
+                    }
+                }
+            }
+        }
+
+        response_list
+            .into_iter()
+            .nth(accepted_index)
+            .unwrap_or(Err(Error::QuorumNotReached(agreed, quorum)))
+    }
+
+    pub fn health_list(&self) -> Vec<EndpointHealth> {
+        let active_index = self.active_index.load(Ordering::Relaxed) % self.endpoints.len();
+
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| EndpointHealth {
+                is_active: index == active_index,
+                ..endpoint.health.lock().unwrap().clone()
+            })
+            .collect()
+    }
+
+    fn rotate(&self, from_index: usize) {
+        let _ = self.active_index.compare_exchange(
+            from_index,
+            (from_index + 1) % self.endpoints.len(),
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::ethereum_rpc::EthereumRpcClient, test_util::mock_node};
+
+    /// Pool of mock nodes that report the given chain IDs, or fail for `None`.
+    async fn endpoint_pool(chain_id_list: &[Option<u64>]) -> EndpointPool<EthereumRpcClient> {
+        let mut endpoints = Vec::new();
+        for chain_id in chain_id_list.iter().copied() {
+            let rpc_url = mock_node(move |method, _| match method {
+                "eth_chainId" => {
+                    chain_id.map(|chain_id| serde_json::json!(format!("{:#x}", chain_id)))
+                }
+                _ => None,
+            })
+            .await;
+            let ethereum_rpc_client = EthereumRpcClient::new(&rpc_url).unwrap();
+
+            endpoints.push((rpc_url, ethereum_rpc_client));
+        }
+
+        EndpointPool::new(endpoints).unwrap()
+    }
+
+    #[tokio::test]
+    async fn accept_answer_of_quorum() {
+        let endpoint_pool = endpoint_pool(&[Some(1), Some(2), Some(1)]).await;
+
+        let chain_id = endpoint_pool
+            .request_quorum(2, EthereumRpcClient::chain_id)
+            .await
+            .unwrap();
+        assert_eq!(chain_id, 1);
+
+        let health_list = endpoint_pool.health_list();
+        assert_eq!(health_list[0].disagreement_count, 0);
+        assert_eq!(health_list[1].disagreement_count, 1);
+        assert_eq!(health_list[2].disagreement_count, 0);
+    }
+
+    #[tokio::test]
+    async fn reject_answer_below_quorum() {
+        let endpoint_pool = endpoint_pool(&[Some(1), Some(2), None]).await;
+
+        assert!(matches!(
+            endpoint_pool
+                .request_quorum(2, EthereumRpcClient::chain_id)
+                .await,
+            Err(Error::QuorumNotReached(1, 2))
+        ));
+        assert_eq!(endpoint_pool.health_list()[2].failure_count, 1);
+    }
+
+    #[tokio::test]
+    async fn reject_conflicting_quorum() {
+        let endpoint_pool = endpoint_pool(&[Some(1), Some(2), Some(1), Some(2)]).await;
+
+        assert!(matches!(
+            endpoint_pool
+                .request_quorum(2, EthereumRpcClient::chain_id)
+                .await,
+            Err(Error::ConflictingQuorum(2))
+        ));
+    }
+
+    #[tokio::test]
+    async fn fail_over_to_next_endpoint() {
+        let endpoint_pool = endpoint_pool(&[None, Some(1), Some(1)]).await;
+
+        let chain_id = endpoint_pool
+            .request(EthereumRpcClient::chain_id)
+            .await
+            .unwrap();
+        assert_eq!(chain_id, 1);
+
+        let health_list = endpoint_pool.health_list();
+        assert!(!health_list[0].is_active);
+        assert_eq!(health_list[0].failure_count, 1);
+        assert_eq!(health_list[0].consecutive_failures, 1);
+        assert!(health_list[0].last_error.is_some());
+        assert!(health_list[0].last_failure_at.is_some());
+        assert!(health_list[1].is_active);
+        assert_eq!(health_list[1].success_count, 1);
+        assert_eq!(health_list[1].failure_count, 0);
+
+        // The next request stays on the endpoint that answered.
+        endpoint_pool
+            .request(EthereumRpcClient::chain_id)
+            .await
+            .unwrap();
+        let health_list = endpoint_pool.health_list();
+        assert_eq!(health_list[0].failure_count, 1);
+        assert_eq!(health_list[1].success_count, 2);
+    }
+
+    #[tokio::test]
+    async fn fail_when_every_endpoint_fails() {
+        let endpoint_pool = endpoint_pool(&[None, None]).await;
+
+        assert!(endpoint_pool
+            .request(EthereumRpcClient::chain_id)
+            .await
+            .is_err());
+
+        // Every endpoint was tried once, and the pool wrapped around.
+        let health_list = endpoint_pool.health_list();
+        assert_eq!(health_list[0].failure_count, 1);
+        assert_eq!(health_list[1].failure_count, 1);
+        assert!(health_list[0].is_active);
+    }
+
+    #[tokio::test]
+    async fn reset_consecutive_failures_on_success() {
+        let endpoint_pool = endpoint_pool(&[Some(1)]).await;
+
+        endpoint_pool.report(Err(&Error::EmptyEndpointList));
+        endpoint_pool.report(Err(&Error::EmptyEndpointList));
+        assert_eq!(endpoint_pool.health_list()[0].consecutive_failures, 2);
+
+        endpoint_pool
+            .request(EthereumRpcClient::chain_id)
+            .await
+            .unwrap();
+        let health_list = endpoint_pool.health_list();
+        assert_eq!(health_list[0].failure_count, 2);
+        assert_eq!(health_list[0].consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn rotate_once_for_concurrent_failures() {
+        let endpoint_pool = endpoint_pool(&[Some(1), Some(1), Some(1)]).await;
+
+        // Two requests that both failed on the first endpoint move the pool
+        // to the second one, not past it.
+        endpoint_pool.rotate(0);
+        endpoint_pool.rotate(0);
+        assert!(endpoint_pool.health_list()[1].is_active);
+
+        endpoint_pool.rotate(1);
+        endpoint_pool.rotate(2);
+        assert!(endpoint_pool.health_list()[0].is_active);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fail_over_on_timeout() {
+        let endpoint_pool = EndpointPool::new(vec![
+            ("http://hanging".to_owned(), false),
+            ("http://answering".to_owned(), true),
+        ])
+        .unwrap();
+
+        // The first endpoint never answers. With the clock paused, the
+        // timeout elapses as soon as nothing else can make progress.
+        let response = endpoint_pool
+            .request(|is_answering: &bool| {
+                let is_answering = *is_answering;
+
+                async move {
+                    if !is_answering {
+                        std::future::pending::<()>().await;
+                    }
+
+                    Ok(is_answering)
+                }
+            })
+            .await;
+        assert!(response.unwrap());
+
+        let health_list = endpoint_pool.health_list();
+        assert_eq!(health_list[0].failure_count, 1);
+        assert!(health_list[0]
+            .last_error
+            .as_ref()
+            .is_some_and(|last_error| last_error.contains("EndpointTimeout")));
+        assert!(health_list[1].is_active);
+        assert_eq!(health_list[1].success_count, 1);
+    }
+}
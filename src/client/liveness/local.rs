@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, fs, sync::Arc};
+
+use futures::future::BoxFuture;
+use radius_sdk::signature::PrivateKeySigner;
+
+use super::ClusterMembership;
+use crate::{error::Error, state::AppState, types::*};
+
+/// Liveness client for local ordering. Cluster membership comes from the
+/// `LivenessLocal` payload instead of a liveness contract.
+pub struct LivenessClient {
+    inner: Arc<LivenessClientInner>,
+}
+
+struct LivenessClientInner {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    liveness_info: LivenessLocal,
+}
+
+impl Clone for LivenessClient {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl LivenessClient {
+    pub fn platform(&self) -> Platform {
+        self.inner.platform
+    }
+
+    pub fn liveness_service_provider(&self) -> LivenessServiceProvider {
+        self.inner.liveness_service_provider
+    }
+
+    pub fn new(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        liveness_info: LivenessLocal,
+    ) -> Result<Self, Error> {
+        if liveness_info.cluster_list.is_none() && liveness_info.cluster_list_path.is_none() {
+            return Err(Error::EmptyLocalClusterList);
+        }
+
+        let inner = LivenessClientInner {
+            platform,
+            liveness_service_provider,
+            liveness_info,
+        };
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    pub fn initialize(
+        context: AppState,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        liveness_info: LivenessLocal,
+    ) {
+        let generation = context.next_liveness_generation();
+        let handle = tokio::spawn({
+            let context = context.clone();
+
+            async move {
+                if super::initialize_with_retry(
+                    &context,
+                    platform,
+                    liveness_service_provider,
+                    generation,
+                    || {
+                        Self::connect(
+                            &context,
+                            platform,
+                            liveness_service_provider,
+                            &liveness_info,
+                        )
+                    },
+                )
+                .await
+                .is_some()
+                {
+                    tracing::info!(
+                        "Initialized the local liveness client for {:?}, {:?}",
+                        platform,
+                        liveness_service_provider
+                    );
+                }
+            }
+        });
+
+        context.set_liveness_task(platform, liveness_service_provider, generation, handle);
+    }
+
+    /// Create the signer and the liveness client and make sure that the cluster
+    /// list can be read.
+    async fn connect(
+        context: &AppState,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        liveness_info: &LivenessLocal,
+    ) -> Result<Self, Error> {
+        let signing_key = &context.config().signing_key;
+        let signer = PrivateKeySigner::from_str(platform.into(), signing_key)?;
+        context.add_signer(platform, signer).await?;
+
+        let liveness_client =
+            Self::new(platform, liveness_service_provider, liveness_info.clone())?;
+        liveness_client.cluster_list()?;
+
+        Ok(liveness_client)
+    }
+
+    /// Map of cluster IDs to the addresses of their tx_orderers.
+    pub fn cluster_list(&self) -> Result<BTreeMap<String, Vec<String>>, Error> {
+        let cluster_list = match (
+            &self.inner.liveness_info.cluster_list,
+            &self.inner.liveness_info.cluster_list_path,
+        ) {
+            (_, Some(cluster_list_path)) => {
+                let cluster_list_string =
+                    fs::read_to_string(cluster_list_path).map_err(Error::LoadLocalClusterList)?;
+
+                serde_json::from_str(&cluster_list_string).map_err(Error::ParseLocalClusterList)?
+            }
+            (Some(cluster_list), None) => cluster_list.clone(),
+            (None, None) => return Err(Error::EmptyLocalClusterList),
+        };
+
+        Ok(cluster_list)
+    }
+
+    pub fn get_tx_orderer_list(&self, cluster_id: &str) -> Result<ClusterMembership, Error> {
+        Ok(ClusterMembership::new(
+            self.cluster_list()?.remove(cluster_id).unwrap_or_default(),
+        ))
+    }
+}
+
+impl super::LivenessClient for LivenessClient {
+    fn platform(&self) -> Platform {
+        self.inner.platform
+    }
+
+    fn liveness_service_provider(&self) -> LivenessServiceProvider {
+        self.inner.liveness_service_provider
+    }
+
+    /// Local cluster membership has no history, so every lookup is made at
+    /// block zero.
+    fn finalized_block(&self) -> BoxFuture<'_, Result<u64, Error>> {
+        Box::pin(async { Ok(0) })
+    }
+
+    fn tx_orderer_list<'a>(
+        &'a self,
+        cluster_id: &'a str,
+        _block_number: u64,
+    ) -> BoxFuture<'a, Result<ClusterMembership, Error>> {
+        Box::pin(async move { self.get_tx_orderer_list(cluster_id) })
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use futures::future::BoxFuture;
+use radius_sdk::signature::Address;
+
+use super::ClusterMembership;
+use crate::{error::Error, types::*, util::normalize_address};
+
+/// In-memory liveness client for tests. Cluster membership and the finalized
+/// block are set by the test instead of being read from a contract.
+#[derive(Clone)]
+pub struct LivenessClient {
+    inner: Arc<LivenessClientInner>,
+}
+
+struct LivenessClientInner {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    finalized_block: AtomicU64,
+    cluster_list: Mutex<BTreeMap<String, Vec<String>>>,
+}
+
+impl LivenessClient {
+    pub fn new(platform: Platform, liveness_service_provider: LivenessServiceProvider) -> Self {
+        let inner = LivenessClientInner {
+            platform,
+            liveness_service_provider,
+            finalized_block: AtomicU64::new(0),
+            cluster_list: Mutex::new(BTreeMap::new()),
+        };
+
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    pub fn set_finalized_block(&self, block_number: u64) {
+        self.inner
+            .finalized_block
+            .store(block_number, Ordering::Relaxed);
+    }
+
+    pub fn add_tx_orderer(&self, cluster_id: &str, tx_orderer_address: &Address) {
+        self.inner
+            .cluster_list
+            .lock()
+            .unwrap()
+            .entry(cluster_id.to_owned())
+            .or_default()
+            .push(tx_orderer_address.as_hex_string());
+    }
+
+    pub fn remove_tx_orderer(&self, cluster_id: &str, tx_orderer_address: &Address) {
+        let tx_orderer_address = normalize_address(tx_orderer_address.as_hex_string());
+
+        if let Some(tx_orderer_list) = self.inner.cluster_list.lock().unwrap().get_mut(cluster_id) {
+            tx_orderer_list.retain(|address| normalize_address(address) != tx_orderer_address);
+        }
+    }
+}
+
+impl super::LivenessClient for LivenessClient {
+    fn platform(&self) -> Platform {
+        self.inner.platform
+    }
+
+    fn liveness_service_provider(&self) -> LivenessServiceProvider {
+        self.inner.liveness_service_provider
+    }
+
+    fn finalized_block(&self) -> BoxFuture<'_, Result<u64, Error>> {
+        Box::pin(async { Ok(self.inner.finalized_block.load(Ordering::Relaxed)) })
+    }
+
+    fn tx_orderer_list<'a>(
+        &'a self,
+        cluster_id: &'a str,
+        _block_number: u64,
+    ) -> BoxFuture<'a, Result<ClusterMembership, Error>> {
+        Box::pin(async move {
+            let cluster_list = self.inner.cluster_list.lock().unwrap();
+
+            Ok(ClusterMembership::new(
+                cluster_list.get(cluster_id).cloned().unwrap_or_default(),
+            ))
+        })
+    }
+}
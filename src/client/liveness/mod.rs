@@ -1 +1,216 @@
+pub mod cache;
+pub mod endpoint;
+pub mod local;
+#[cfg(test)]
+pub mod mock;
 pub mod radius;
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use futures::future::BoxFuture;
+use radius_sdk::signature::Address;
+
+use crate::{
+    error::Error,
+    state::AppState,
+    types::{
+        EndpointHealth, LivenessClientStatus, LivenessInfoPayload, LivenessServiceProvider,
+        Platform, QueryCacheStats,
+    },
+    util::normalize_address,
+};
+
+/// Attempts after which the client is reported as failed. It keeps retrying
+/// afterwards.
+const INITIALIZE_ATTEMPTS_BEFORE_FAILED: u32 = 10;
+const INITIALIZE_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const INITIALIZE_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Source of cluster membership for a (platform, liveness service provider)
+/// pair. The registry only talks to liveness clients through this trait, so a
+/// new provider only needs an implementation and a branch in the
+/// initialization.
+pub trait LivenessClient: Send + Sync + 'static {
+    fn platform(&self) -> Platform;
+
+    fn liveness_service_provider(&self) -> LivenessServiceProvider;
+
+    /// The block cluster membership is checked at, following the configured
+    /// finality policy.
+    fn finalized_block(&self) -> BoxFuture<'_, Result<u64, Error>>;
+
+    fn tx_orderer_list<'a>(
+        &'a self,
+        cluster_id: &'a str,
+        block_number: u64,
+    ) -> BoxFuture<'a, Result<ClusterMembership, Error>>;
+
+    /// Check that the tx_orderer meets the requirements of the validation
+    /// service provider at the given block. Clients without one accept every
+    /// tx_orderer.
+    fn validate_tx_orderer<'a>(
+        &'a self,
+        _tx_orderer_address: &'a Address,
+        _block_number: u64,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Hit and miss counts of the contract query cache, if the client has one.
+    fn cache_stats(&self) -> Option<QueryCacheStats> {
+        None
+    }
+
+    /// Health of the endpoints the client talks to, for debugging.
+    fn endpoint_health_list(&self) -> Vec<EndpointHealth> {
+        Vec::new()
+    }
+}
+
+/// Normalized addresses of the tx_orderers in a cluster, sorted so that lists
+/// from different endpoints can be compared.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClusterMembership(Vec<String>);
+
+impl ClusterMembership {
+    pub fn new<T>(address_list: impl IntoIterator<Item = T>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let mut address_list: Vec<String> =
+            address_list.into_iter().map(normalize_address).collect();
+        address_list.sort();
+        address_list.dedup();
+
+        Self(address_list)
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.contains_hex(address.as_hex_string())
+    }
+
+    pub fn contains_hex(&self, address: impl AsRef<str>) -> bool {
+        self.0.contains(&normalize_address(address))
+    }
+
+    pub fn into_inner(self) -> Vec<String> {
+        self.0
+    }
+}
+
+/// Start the liveness client matching the payload. A client already running
+/// for the same platform and liveness service provider is replaced.
+pub fn initialize(
+    context: AppState,
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    liveness_info_payload: LivenessInfoPayload,
+) {
+    match liveness_info_payload {
+        LivenessInfoPayload::Ethereum(liveness_info) => {
+            radius::LivenessClient::initialize(
+                context,
+                platform,
+                liveness_service_provider,
+                liveness_info,
+            );
+        }
+        LivenessInfoPayload::Local(liveness_info) => {
+            local::LivenessClient::initialize(
+                context,
+                platform,
+                liveness_service_provider,
+                liveness_info,
+            );
+        }
+    }
+}
+
+/// Run `connect` until it returns a liveness client, backing off exponentially
+/// between attempts, and register the client in the app state in place of the
+/// client of an older generation. Returns `None` when a client of a newer
+/// generation was registered in the meantime.
+///
+/// The progress is reported through the liveness client status so that a
+/// misconfigured entry shows up in `get_liveness_infos` instead of failing
+/// silently. After a number of attempts the client is reported as failed, but
+/// the attempts go on with the capped backoff so that an outage of the
+/// liveness chain does not need a restart to recover from.
+pub async fn initialize_with_retry<T, F, Fut>(
+    context: &AppState,
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    generation: u64,
+    mut connect: F,
+) -> Option<T>
+where
+    T: LivenessClient + Clone,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut backoff = INITIALIZE_INITIAL_BACKOFF;
+    let mut last_error = None;
+    let mut attempt: u32 = 1;
+
+    loop {
+        let status = match (
+            attempt > INITIALIZE_ATTEMPTS_BEFORE_FAILED,
+            last_error.clone(),
+        ) {
+            (true, Some(reason)) => LivenessClientStatus::Failed { attempt, reason },
+            (_, last_error) => LivenessClientStatus::Initializing {
+                attempt,
+                last_error,
+            },
+        };
+        context
+            .set_liveness_client_status(platform, liveness_service_provider, status)
+            .await;
+
+        match connect().await {
+            Ok(liveness_client) => {
+                if !context
+                    .activate_liveness_client(
+                        platform,
+                        liveness_service_provider,
+                        generation,
+                        Arc::new(liveness_client.clone()),
+                    )
+                    .await
+                {
+                    tracing::info!(
+                        "Dropped a superseded liveness client for {:?}, {:?}",
+                        platform,
+                        liveness_service_provider
+                    );
+                    return None;
+                }
+
+                context
+                    .set_liveness_client_status(
+                        platform,
+                        liveness_service_provider,
+                        LivenessClientStatus::Ready,
+                    )
+                    .await;
+
+                return Some(liveness_client);
+            }
+            Err(error) => {
+                let log_message = format!(
+                    "Failed to initialize the liveness client for {:?}, {:?} (attempt {}): {:?}",
+                    platform, liveness_service_provider, attempt, error
+                );
+                match attempt < INITIALIZE_ATTEMPTS_BEFORE_FAILED {
+                    true => tracing::warn!("{}", log_message),
+                    false => tracing::error!("{}", log_message),
+                }
+                last_error = Some(error.to_string());
+            }
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(INITIALIZE_MAX_BACKOFF);
+        attempt = attempt.saturating_add(1);
+    }
+}
@@ -1,8 +1,44 @@
-use std::sync::Arc;
+use std::{fmt::Debug, future::Future, sync::Arc, time::Duration};
 
-use radius_sdk::{liveness::radius::publisher::Publisher, signature::PrivateKeySigner};
+use futures::future::BoxFuture;
+use radius_sdk::{
+    liveness::radius::{
+        publisher::Publisher,
+        subscriber::Subscriber,
+        types::{Events, Liveness::LivenessEvents},
+    },
+    signature::{Address, PrivateKeySigner},
+};
+use tokio::sync::Mutex;
 
-use crate::{error::Error, state::AppState, types::*};
+use super::{
+    cache::QueryCache,
+    endpoint::{Endpoint, EndpointPool},
+    ClusterMembership,
+};
+use crate::{
+    client::{
+        abi,
+        ethereum_rpc::{BlockHeader, EthereumRpcClient},
+        storage_proof::{self, AccountProof, Word},
+    },
+    error::Error,
+    state::AppState,
+    types::*,
+    util::normalize_address,
+};
+
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of blocks requested at once when looking for logs to backfill.
+const BACKFILL_BLOCK_RANGE: u64 = 1000;
+
+/// Longest tx_orderer list read from the contract storage. The length comes
+/// from the storage itself, so it bounds the proofs requested for a cluster.
+const MAX_TX_ORDERER_LIST_LENGTH: u64 = 1024;
+
+/// Number of storage slots proven with a single `eth_getProof` request.
+const STORAGE_PROOF_BATCH_SIZE: usize = 128;
 
 pub struct LivenessClient {
     inner: Arc<LivenessClientInner>,
@@ -11,7 +47,84 @@ pub struct LivenessClient {
 struct LivenessClientInner {
     platform: Platform,
     liveness_service_provider: LivenessServiceProvider,
+    rpc_endpoints: EndpointPool<RpcEndpoint>,
+    websocket_endpoints: EndpointPool<()>,
+    contract_address: String,
+    quorum: Option<usize>,
+    tx_orderer_list_slot: Option<u64>,
+    finality_policy: FinalityPolicy,
+    cache: QueryCache,
+    validation_info: Option<ValidationInfo>,
+    /// Serializes block processing between the backfill and the event
+    /// listener so that the checkpoint is never updated concurrently.
+    block_processing_lock: Mutex<()>,
+}
+
+pub struct RpcEndpoint {
     publisher: Publisher,
+    ethereum_rpc_client: EthereumRpcClient,
+}
+
+impl RpcEndpoint {
+    /// Check that the endpoint serves the chain with the given chain ID.
+    async fn check_chain_id(endpoint: &Endpoint<Self>, chain_id: u64) -> Result<(), Error> {
+        let endpoint_chain_id = endpoint.client().ethereum_rpc_client().chain_id().await?;
+        if endpoint_chain_id != chain_id {
+            tracing::error!(
+                "Liveness endpoint {} serves chain {} instead of {}",
+                endpoint.url(),
+                endpoint_chain_id,
+                chain_id
+            );
+            return Err(Error::ChainIdMismatch(
+                endpoint.url().to_owned(),
+                chain_id,
+                endpoint_chain_id,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn publisher(&self) -> &Publisher {
+        &self.publisher
+    }
+
+    pub fn ethereum_rpc_client(&self) -> &EthereumRpcClient {
+        &self.ethereum_rpc_client
+    }
+
+    pub async fn get_block_number(&self) -> Result<u64, Error> {
+        self.publisher
+            .get_block_number()
+            .await
+            .map_err(|error| Error::LivenessClient(error.into()))
+    }
+
+    pub async fn get_block_margin(&self) -> Result<u64, Error> {
+        self.publisher
+            .get_block_margin()
+            .await
+            .map_err(|error| Error::LivenessClient(error.into()))?
+            .try_into()
+            .map_err(|_| Error::InvalidBlockMargin)
+    }
+
+    pub async fn get_tx_orderer_list(
+        &self,
+        cluster_id: &str,
+        block_number: u64,
+    ) -> Result<ClusterMembership, Error> {
+        let tx_orderer_list = self
+            .publisher
+            .get_tx_orderer_list(cluster_id, block_number)
+            .await
+            .map_err(|error| Error::LivenessClient(error.into()))?;
+
+        Ok(ClusterMembership::new(
+            tx_orderer_list.iter().map(|address| address.to_string()),
+        ))
+    }
 }
 
 impl Clone for LivenessClient {
@@ -31,8 +144,332 @@ impl LivenessClient {
         self.inner.liveness_service_provider
     }
 
+    pub fn rpc_endpoints(&self) -> &EndpointPool<RpcEndpoint> {
+        &self.inner.rpc_endpoints
+    }
+
+    pub fn websocket_endpoints(&self) -> &EndpointPool<()> {
+        &self.inner.websocket_endpoints
+    }
+
+    pub fn cache(&self) -> &QueryCache {
+        &self.inner.cache
+    }
+
+    /// The publisher of the RPC endpoint currently in use.
     pub fn publisher(&self) -> &Publisher {
-        &self.inner.publisher
+        self.rpc_endpoints().active().client().publisher()
+    }
+
+    /// Send a contract query to the active RPC endpoint, or to all of them
+    /// when a quorum is configured.
+    async fn request<'a, R, F, Fut>(&'a self, request: F) -> Result<R, Error>
+    where
+        R: PartialEq + Debug,
+        F: Fn(&'a RpcEndpoint) -> Fut,
+        Fut: Future<Output = Result<R, Error>> + 'a,
+    {
+        match self.inner.quorum {
+            Some(quorum) => self.rpc_endpoints().request_quorum(quorum, request).await,
+            None => self.rpc_endpoints().request(request).await,
+        }
+    }
+
+    pub async fn get_block_number(&self) -> Result<u64, Error> {
+        if let Some(block_number) = self.cache().block_number() {
+            return Ok(block_number);
+        }
+
+        let block_number = self.fetch_block_number().await?;
+        self.cache().set_block_number(block_number);
+
+        Ok(block_number)
+    }
+
+    /// The latest block number. With a quorum, endpoints rarely share the
+    /// exact head, so this is the highest block that at least `quorum` of
+    /// them have reached.
+    async fn fetch_block_number(&self) -> Result<u64, Error> {
+        let Some(quorum) = self.inner.quorum else {
+            return self
+                .rpc_endpoints()
+                .request(RpcEndpoint::get_block_number)
+                .await;
+        };
+
+        let mut block_number_list: Vec<u64> = self
+            .rpc_endpoints()
+            .request_all(RpcEndpoint::get_block_number)
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+        block_number_list.sort_unstable_by(|a, b| b.cmp(a));
+
+        block_number_list
+            .get(quorum - 1)
+            .copied()
+            .ok_or(Error::QuorumNotReached(block_number_list.len(), quorum))
+    }
+
+    pub async fn get_block_margin(&self) -> Result<u64, Error> {
+        if let Some(block_margin) = self.cache().block_margin() {
+            return Ok(block_margin);
+        }
+
+        let block_margin = self.request(RpcEndpoint::get_block_margin).await?;
+        self.cache().set_block_margin(block_margin);
+
+        Ok(block_margin)
+    }
+
+    pub async fn get_finalized_block(&self) -> Result<u64, Error> {
+        let block_number = self.get_block_number().await?;
+
+        self.finalized_block_at(block_number).await
+    }
+
+    /// The block the finality policy selects when `block_number` is the
+    /// latest block.
+    async fn finalized_block_at(&self, block_number: u64) -> Result<u64, Error> {
+        let block_margin = match self.inner.finality_policy {
+            FinalityPolicy::BlockMargin => self.get_block_margin().await?,
+            _ => 0,
+        };
+
+        Ok(self
+            .inner
+            .finality_policy
+            .block_number(block_number, block_margin))
+    }
+
+    pub async fn get_tx_orderer_list(
+        &self,
+        cluster_id: &str,
+        block_number: u64,
+    ) -> Result<ClusterMembership, Error> {
+        if let Some(tx_orderer_list) = self.cache().tx_orderer_list(cluster_id, block_number) {
+            return Ok(tx_orderer_list);
+        }
+
+        let tx_orderer_list = match self.inner.tx_orderer_list_slot {
+            Some(tx_orderer_list_slot) => {
+                self.get_verified_tx_orderer_list(cluster_id, block_number, tx_orderer_list_slot)
+                    .await?
+            }
+            None => {
+                self.request(|endpoint| endpoint.get_tx_orderer_list(cluster_id, block_number))
+                    .await?
+            }
+        };
+        self.cache()
+            .set_tx_orderer_list(cluster_id, block_number, tx_orderer_list.clone());
+
+        Ok(tx_orderer_list)
+    }
+
+    pub async fn get_block_header(&self, block_number: u64) -> Result<BlockHeader, Error> {
+        self.rpc_endpoints()
+            .request(|endpoint| endpoint.ethereum_rpc_client().block_header(block_number))
+            .await
+    }
+
+    async fn call(&self, to: &str, calldata: Vec<u8>, block_number: u64) -> Result<Vec<u8>, Error> {
+        self.request(|endpoint| {
+            endpoint
+                .ethereum_rpc_client()
+                .call(to, &calldata, block_number)
+        })
+        .await
+    }
+
+    /// Check that the tx_orderer is a registered operator of the validation
+    /// service provider, has opted in to the network and, if required, has
+    /// at least the minimum stake.
+    pub async fn validate_operator(
+        &self,
+        tx_orderer_address: &Address,
+        block_number: u64,
+    ) -> Result<(), Error> {
+        let Some(validation_info) = &self.inner.validation_info else {
+            return Ok(());
+        };
+
+        let operator = abi::encode_address(tx_orderer_address.as_hex_string())?;
+        let network = abi::encode_address(&validation_info.network_address)?;
+
+        let (is_operator_signature, opt_in_signature) =
+            match validation_info.validation_service_provider {
+                ValidationServiceProvider::EigenLayer => {
+                    ("isOperator(address)", "avsOperatorStatus(address,address)")
+                }
+                ValidationServiceProvider::Symbiotic => {
+                    ("isEntity(address)", "isOptedIn(address,address)")
+                }
+            };
+
+        let is_operator = self
+            .call(
+                &validation_info.operator_registry_address,
+                abi::encode_call(is_operator_signature, &[operator]),
+                block_number,
+            )
+            .await?;
+        if !abi::decode_bool(&is_operator)? {
+            return Err(Error::NotRegisteredOperator);
+        }
+
+        // `avsOperatorStatus` returns 1 (REGISTERED) and `isOptedIn` returns
+        // true, so both read as a non-zero last byte.
+        let opt_in_argument_list = match validation_info.validation_service_provider {
+            ValidationServiceProvider::EigenLayer => [network, operator],
+            ValidationServiceProvider::Symbiotic => [operator, network],
+        };
+        let is_opted_in = self
+            .call(
+                &validation_info.opt_in_service_address,
+                abi::encode_call(opt_in_signature, &opt_in_argument_list),
+                block_number,
+            )
+            .await?;
+        if abi::decode_u8(&is_opted_in)? != 1 {
+            return Err(Error::NotOptedInToNetwork);
+        }
+
+        let Some(minimum_stake) = validation_info.minimum_stake else {
+            return Ok(());
+        };
+
+        let subnetwork = abi::encode_subnetwork(
+            &validation_info.network_address,
+            validation_info.subnetwork_identifier,
+        )?;
+
+        let mut stake: u128 = 0;
+        for stake_address in validation_info.stake_address_list.iter() {
+            let (to, calldata) = match validation_info.validation_service_provider {
+                ValidationServiceProvider::EigenLayer => (
+                    validation_info.operator_registry_address.as_str(),
+                    abi::encode_call(
+                        "operatorShares(address,address)",
+                        &[operator, abi::encode_address(stake_address)?],
+                    ),
+                ),
+                ValidationServiceProvider::Symbiotic => (
+                    stake_address.as_str(),
+                    abi::encode_call("stake(bytes32,address)", &[subnetwork, operator]),
+                ),
+            };
+
+            let output = self.call(to, calldata, block_number).await?;
+            stake = stake.saturating_add(abi::decode_u128_saturating(&output)?);
+        }
+
+        if stake < minimum_stake {
+            return Err(Error::InsufficientStake(stake, minimum_stake));
+        }
+
+        Ok(())
+    }
+
+    /// Read the tx_orderer list of the cluster from the contract storage and
+    /// verify it against the state root of the block.
+    ///
+    /// The state root is only as trustworthy as the header it comes from, so
+    /// the header is read through the quorum, which `new` requires for
+    /// storage proofs. The proofs themselves can come from any endpoint.
+    async fn get_verified_tx_orderer_list(
+        &self,
+        cluster_id: &str,
+        block_number: u64,
+        tx_orderer_list_slot: u64,
+    ) -> Result<ClusterMembership, Error> {
+        let block_header = self.fetch_block_header(block_number).await?;
+        let state_root: Word = storage_proof::decode_hex(&block_header.state_root)?
+            .try_into()
+            .map_err(|_| Error::InvalidStorageProof("invalid state root"))?;
+
+        // `mapping(string => address[])`: the slot of the array holds its
+        // length and the elements follow from the hash of that slot.
+        let array_slot = storage_proof::mapping_slot(
+            cluster_id.as_bytes(),
+            &storage_proof::u64_to_word(tx_orderer_list_slot),
+        );
+        let length = self
+            .get_verified_storage(&state_root, &[array_slot], block_number)
+            .await?;
+        let length = storage_proof::word_to_u64(&length[0])?;
+        if length > MAX_TX_ORDERER_LIST_LENGTH {
+            return Err(Error::TxOrdererListTooLong(length));
+        }
+
+        let element_slot_list: Vec<Word> = (0..length)
+            .map(|index| storage_proof::array_element_slot(&array_slot, index))
+            .collect();
+        let mut element_list = Vec::with_capacity(element_slot_list.len());
+        for slot_list in element_slot_list.chunks(STORAGE_PROOF_BATCH_SIZE) {
+            element_list.extend(
+                self.get_verified_storage(&state_root, slot_list, block_number)
+                    .await?,
+            );
+        }
+
+        Ok(ClusterMembership::new(
+            element_list.iter().map(storage_proof::word_to_address),
+        ))
+    }
+
+    /// Header of the block, read through the quorum when one is configured
+    /// and checked against its hash.
+    async fn fetch_block_header(&self, block_number: u64) -> Result<BlockHeader, Error> {
+        let block_header = self
+            .request(|endpoint| endpoint.ethereum_rpc_client().block_header(block_number))
+            .await?;
+
+        block_header.verify_hash()?;
+        if block_header.block_number()? != block_number {
+            return Err(Error::InvalidBlockHeader("block number mismatch"));
+        }
+
+        Ok(block_header)
+    }
+
+    async fn get_verified_storage(
+        &self,
+        state_root: &Word,
+        slot_list: &[Word],
+        block_number: u64,
+    ) -> Result<Vec<Word>, Error> {
+        if slot_list.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let storage_keys: Vec<String> = slot_list
+            .iter()
+            .map(|slot| format!("0x{}", storage_proof::encode_hex(slot)))
+            .collect();
+        let account_proof: AccountProof = self
+            .rpc_endpoints()
+            .request(|endpoint| {
+                endpoint.ethereum_rpc_client().get_proof(
+                    &self.inner.contract_address,
+                    &storage_keys,
+                    block_number,
+                )
+            })
+            .await?;
+
+        let storage_root = account_proof.verify(state_root, &self.inner.contract_address)?;
+        if account_proof.storage_proof.len() != slot_list.len() {
+            return Err(Error::InvalidStorageProof("storage proof count mismatch"));
+        }
+
+        account_proof
+            .storage_proof
+            .iter()
+            .zip(slot_list)
+            .map(|(proof, slot)| proof.verify(&storage_root, slot))
+            .collect()
     }
 
     pub fn new(
@@ -40,18 +477,70 @@ impl LivenessClient {
         liveness_service_provider: LivenessServiceProvider,
         liveness_info: LivenessRadius,
         signing_key: impl AsRef<str>,
+        finality_policy: FinalityPolicy,
     ) -> Result<Self, Error> {
-        let publisher = Publisher::new(
-            liveness_info.liveness_rpc_url,
-            signing_key,
-            &liveness_info.contract_address,
-        )
-        .map_err(|error| Error::LivenessClient(error.into()))?;
+        let rpc_endpoints = liveness_info
+            .rpc_urls()
+            .into_iter()
+            .map(|rpc_url| {
+                let ethereum_rpc_client = EthereumRpcClient::new(&rpc_url)?;
+                let publisher = Publisher::new(
+                    rpc_url.clone(),
+                    signing_key.as_ref(),
+                    &liveness_info.contract_address,
+                )
+                .map_err(|error| Error::LivenessClient(error.into()))?;
+
+                Ok((
+                    rpc_url,
+                    RpcEndpoint {
+                        publisher,
+                        ethereum_rpc_client,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<(String, RpcEndpoint)>, Error>>()?;
+
+        let websocket_endpoints = liveness_info
+            .websocket_urls()
+            .into_iter()
+            .map(|websocket_url| (websocket_url, ()))
+            .collect();
+
+        // A quorum of at most half of the endpoints could be reached by two
+        // different answers.
+        if let Some(quorum) = liveness_info.quorum {
+            if quorum == 0 || quorum > rpc_endpoints.len() || quorum * 2 <= rpc_endpoints.len() {
+                return Err(Error::InvalidQuorum(quorum, rpc_endpoints.len()));
+            }
+        }
+
+        // A single endpoint could serve proofs against a state root of its own
+        // making, with a header that hashes correctly. Only the agreement of
+        // several endpoints makes the header trustworthy.
+        if liveness_info.tx_orderer_list_slot.is_some() && liveness_info.quorum.unwrap_or(1) < 2 {
+            return Err(Error::UntrustedStateRoot);
+        }
+
+        let mut rpc_endpoints = EndpointPool::new(rpc_endpoints)?;
+        if let Some(chain_id) = platform.chain_id() {
+            rpc_endpoints = rpc_endpoints.with_verifier(move |endpoint| {
+                Box::pin(RpcEndpoint::check_chain_id(endpoint, chain_id))
+            });
+        }
 
         let inner = LivenessClientInner {
             platform,
             liveness_service_provider,
-            publisher,
+            rpc_endpoints,
+            websocket_endpoints: EndpointPool::new(websocket_endpoints)?,
+            contract_address: liveness_info.contract_address.clone(),
+            quorum: liveness_info.quorum,
+            tx_orderer_list_slot: liveness_info.tx_orderer_list_slot,
+            finality_policy,
+            cache: QueryCache::new(Duration::from_secs(liveness_info.block_time())),
+            validation_info: liveness_info.validation_info.clone(),
+            block_processing_lock: Mutex::new(()),
         };
 
         Ok(Self {
@@ -65,38 +554,1040 @@ impl LivenessClient {
         liveness_service_provider: LivenessServiceProvider,
         liveness_info: LivenessRadius,
     ) {
-        tokio::spawn({
+        let generation = context.next_liveness_generation();
+        let handle = tokio::spawn({
             let context = context.clone();
             let liveness_info = liveness_info.clone();
 
             async move {
-                let signing_key = &context.config().signing_key;
-                let signer = PrivateKeySigner::from_str(platform.into(), signing_key).unwrap();
-                context.add_signer(platform, signer).await.unwrap();
-
-                let liveness_client = Self::new(
+                let Some(liveness_client) = super::initialize_with_retry(
+                    &context,
                     platform,
                     liveness_service_provider,
-                    liveness_info,
-                    signing_key,
+                    generation,
+                    || {
+                        Self::connect(
+                            &context,
+                            platform,
+                            liveness_service_provider,
+                            &liveness_info,
+                        )
+                    },
                 )
-                .unwrap();
-
-                context
-                    .add_liveness_client(
-                        platform,
-                        liveness_service_provider,
-                        liveness_client.clone(),
-                    )
-                    .await
-                    .unwrap();
+                .await
+                else {
+                    return;
+                };
 
                 tracing::info!(
                     "Initializing the liveness event listener for {:?}, {:?}..",
                     platform,
                     liveness_service_provider
                 );
+
+                loop {
+                    // Catch up with the blocks produced while the listener was
+                    // not running.
+                    if let Err(error) = liveness_client.backfill().await {
+                        tracing::error!(
+                            "Failed to backfill the liveness blocks for {:?}, {:?}: {:?}",
+                            platform,
+                            liveness_service_provider,
+                            error
+                        );
+                    }
+
+                    let websocket_endpoint = liveness_client.websocket_endpoints().active();
+                    let result = match Subscriber::new(
+                        websocket_endpoint.url().to_owned(),
+                        liveness_info.contract_address.clone(),
+                    ) {
+                        Ok(subscriber) => subscriber
+                            .initialize_event_handler(callback, liveness_client.clone())
+                            .await
+                            .map_err(|error| Error::LivenessClient(error.into())),
+                        Err(error) => Err(Error::LivenessClient(error.into())),
+                    };
+
+                    // The handler only returns once the connection is gone, so
+                    // move on to the next websocket endpoint either way.
+                    let error = result.err().unwrap_or(Error::LivenessEventListenerStopped);
+                    tracing::error!(
+                        "Liveness event listener for {:?}, {:?} on {} stopped: {:?}",
+                        platform,
+                        liveness_service_provider,
+                        websocket_endpoint.url(),
+                        error
+                    );
+                    liveness_client.websocket_endpoints().report(Err(&error));
+
+                    tokio::time::sleep(RECONNECT_INTERVAL).await;
+                }
             }
         });
+
+        context.set_liveness_task(platform, liveness_service_provider, generation, handle);
+    }
+
+    /// Create the signer and the liveness client and make sure that the
+    /// liveness RPC endpoint answers.
+    async fn connect(
+        context: &AppState,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        liveness_info: &LivenessRadius,
+    ) -> Result<Self, Error> {
+        let signing_key = &context.config().signing_key;
+        let signer = PrivateKeySigner::from_str(platform.into(), signing_key)?;
+        context.add_signer(platform, signer).await?;
+
+        let liveness_client = Self::new(
+            platform,
+            liveness_service_provider,
+            liveness_info.clone(),
+            signing_key,
+            context.config().finality_policy,
+        )?;
+        liveness_client.get_block_number().await?;
+
+        if platform.chain_id().is_some() {
+            liveness_client.check_chain_id().await?;
+        }
+
+        Ok(liveness_client)
+    }
+
+    /// Make sure that every RPC endpoint that answers serves the chain the
+    /// platform is configured for, so that a misconfigured endpoint cannot
+    /// feed cluster membership from another chain. Endpoints that do not
+    /// answer yet are checked by the endpoint pool before their first use.
+    async fn check_chain_id(&self) -> Result<(), Error> {
+        let mut first_error = None;
+        let mut is_answered = false;
+        for result in self.rpc_endpoints().verify_all().await {
+            match result {
+                Err(error @ Error::ChainIdMismatch(..)) => return Err(error),
+                Ok(()) => is_answered = true,
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        match first_error {
+            Some(error) if !is_answered => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    /// Catch up with the blocks produced since the checkpoint, for instance
+    /// while the seeder was stopped or the event listener was disconnected.
+    ///
+    /// Cluster membership only changes through transactions to the liveness
+    /// contract, so the registry is only diffed against the contract when the
+    /// contract emitted logs after the finalized block of the checkpoint, and
+    /// then on every block until the latest block is finalized. Without a
+    /// checkpoint, it is always diffed.
+    pub async fn backfill(&self) -> Result<(), Error> {
+        let checkpoint = LivenessCheckpoint::get_or(
+            self.platform(),
+            self.liveness_service_provider(),
+            LivenessCheckpoint::default,
+        )?;
+
+        let block_number = self
+            .rpc_endpoints()
+            .request(|endpoint| endpoint.ethereum_rpc_client().block_number())
+            .await?;
+        let has_contract_logs = match checkpoint.latest_block() {
+            Some(latest_block) => {
+                self.has_contract_logs(latest_block.finalized_block_number + 1, block_number)
+                    .await?
+            }
+            None => true,
+        };
+
+        self.process_latest_block(has_contract_logs.then_some(block_number))
+            .await
+    }
+
+    /// Whether the liveness contract emitted any log in the block range,
+    /// queried in chunks of `BACKFILL_BLOCK_RANGE` blocks.
+    async fn has_contract_logs(
+        &self,
+        from_block_number: u64,
+        to_block_number: u64,
+    ) -> Result<bool, Error> {
+        let mut from = from_block_number;
+        while from <= to_block_number {
+            let to = from
+                .saturating_add(BACKFILL_BLOCK_RANGE - 1)
+                .min(to_block_number);
+            let log_list = self
+                .rpc_endpoints()
+                .request(|endpoint| {
+                    endpoint
+                        .ethereum_rpc_client()
+                        .logs(&self.inner.contract_address, from, to)
+                })
+                .await?;
+
+            if !log_list.is_empty() {
+                tracing::info!(
+                    "Backfilling {} liveness contract logs from block {} for {:?}, {:?}",
+                    log_list.len(),
+                    from,
+                    self.platform(),
+                    self.liveness_service_provider()
+                );
+                return Ok(true);
+            }
+
+            from = to + 1;
+        }
+
+        Ok(false)
+    }
+
+    /// Apply the latest liveness block to the registry.
+    ///
+    /// Journal entries whose finalized block is no longer canonical are rolled
+    /// back first by restoring the entries removed at it. Then only the
+    /// registry entries with pending membership changes are checked against
+    /// the contract, unless the whole registry has to be diffed: after a
+    /// rollback, or until `sweep_until` and any block passed before are
+    /// finalized. The latest block is recorded as the new checkpoint.
+    pub async fn process_latest_block(&self, sweep_until: Option<u64>) -> Result<(), Error> {
+        let _guard = self.inner.block_processing_lock.lock().await;
+
+        let mut checkpoint = LivenessCheckpoint::get_or(
+            self.platform(),
+            self.liveness_service_provider(),
+            LivenessCheckpoint::default,
+        )?;
+
+        let is_rolled_back = self.rollback_orphaned_blocks(&mut checkpoint).await?;
+        if let Some(sweep_until) = sweep_until {
+            checkpoint.set_sweep_until(sweep_until);
+        }
+
+        let block_number = self
+            .rpc_endpoints()
+            .request(|endpoint| endpoint.ethereum_rpc_client().block_number())
+            .await?;
+        let block_header = self.get_block_header(block_number).await?;
+        if !is_rolled_back
+            && sweep_until.is_none()
+            && checkpoint
+                .latest_block()
+                .is_some_and(|latest_block| latest_block.block_hash == block_header.hash)
+        {
+            return Ok(());
+        }
+
+        let finalized_block_number = self.finalized_block_at(block_number).await?;
+        let removed = match is_rolled_back || checkpoint.sweep_until().is_some() {
+            true => {
+                self.prune_deregistered_tx_orderers(block_number, finalized_block_number)
+                    .await?
+            }
+            false => {
+                let mut removed = Vec::new();
+                for change in checkpoint.pending_change_list() {
+                    removed.extend(
+                        self.prune_tx_orderers(
+                            &change.cluster_id,
+                            [&change.tx_orderer_address],
+                            block_number,
+                            finalized_block_number,
+                        )
+                        .await?,
+                    );
+                }
+
+                removed
+            }
+        };
+
+        if !removed.is_empty() {
+            let finalized_block_header = self.get_block_header(finalized_block_number).await?;
+            checkpoint.push(ProcessedBlock {
+                block_number: finalized_block_number,
+                block_hash: finalized_block_header.hash,
+                removed,
+            });
+        }
+        checkpoint.settle(finalized_block_number);
+
+        checkpoint.set_latest_block(LatestBlock {
+            block_number,
+            block_hash: block_header.hash,
+            finalized_block_number,
+        });
+        LivenessCheckpoint::put(
+            &checkpoint,
+            self.platform(),
+            self.liveness_service_provider(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Restore the entries removed at journaled finalized blocks that are no
+    /// longer canonical, and drop cached queries when the latest processed
+    /// block was reorganized. Returns whether any entry was rolled back.
+    async fn rollback_orphaned_blocks(
+        &self,
+        checkpoint: &mut LivenessCheckpoint,
+    ) -> Result<bool, Error> {
+        if let Some(latest_block) = checkpoint.latest_block() {
+            let canonical_header = self.get_block_header(latest_block.block_number).await?;
+
+            if canonical_header.hash != latest_block.block_hash {
+                tracing::warn!(
+                    "Liveness block {} ({}) was reorganized for {:?}, {:?}",
+                    latest_block.block_number,
+                    latest_block.block_hash,
+                    self.platform(),
+                    self.liveness_service_provider()
+                );
+                self.cache().invalidate();
+            }
+        }
+
+        let mut is_rolled_back = false;
+        while let Some(latest) = checkpoint.latest() {
+            let canonical_header = self.get_block_header(latest.block_number).await?;
+
+            if canonical_header.hash == latest.block_hash {
+                break;
+            }
+
+            if let Some(orphaned) = checkpoint.pop() {
+                is_rolled_back = true;
+                self.cache().invalidate();
+
+                tracing::warn!(
+                    "Rolling back orphaned liveness block {} ({}) for {:?}, {:?}",
+                    orphaned.block_number,
+                    orphaned.block_hash,
+                    self.platform(),
+                    self.liveness_service_provider()
+                );
+
+                for tx_orderer_rpc_info in orphaned.removed.iter() {
+                    tx_orderer_rpc_info.register()?;
+
+                    TxOrdererHistoryEntry::from_rpc_info(
+                        TxOrdererAction::Restore,
+                        tx_orderer_rpc_info,
+                    )
+                    .with_block_number(orphaned.block_number)
+                    .append(tx_orderer_rpc_info.tx_orderer_address())?;
+                }
+            }
+        }
+
+        Ok(is_rolled_back)
+    }
+
+    /// Remove the registry entries of tx_orderers that are no longer part of
+    /// their cluster in the liveness contract at the finalized block and
+    /// return the removed entries. Entries that already left at the latest
+    /// block are marked as draining.
+    pub async fn prune_deregistered_tx_orderers(
+        &self,
+        latest_block_number: u64,
+        block_number: u64,
+    ) -> Result<Vec<TxOrdererRpcInfo>, Error> {
+        let mut removed = Vec::new();
+
+        let cluster_id_list = ClusterIdList::get_or(ClusterIdList::default)?;
+        for (platform, liveness_service_provider, cluster_id) in cluster_id_list.iter() {
+            if *platform != self.platform()
+                || *liveness_service_provider != self.liveness_service_provider()
+            {
+                continue;
+            }
+
+            let tx_orderer_list = ClusterTxOrdererList::get_or(
+                *platform,
+                *liveness_service_provider,
+                cluster_id,
+                ClusterTxOrdererList::default,
+            )?;
+
+            removed.extend(
+                self.prune_tx_orderers(
+                    cluster_id,
+                    tx_orderer_list.iter(),
+                    latest_block_number,
+                    block_number,
+                )
+                .await?,
+            );
+        }
+
+        Ok(removed)
+    }
+
+    /// Apply the cluster membership at the finalized block and the latest block
+    /// to the given registry entries of the cluster and return the removed
+    /// entries.
+    async fn prune_tx_orderers<'a>(
+        &self,
+        cluster_id: &str,
+        tx_orderer_address_list: impl IntoIterator<Item = &'a Address>,
+        latest_block_number: u64,
+        block_number: u64,
+    ) -> Result<Vec<TxOrdererRpcInfo>, Error> {
+        let mut removed = Vec::new();
+
+        let registered_list = self.get_tx_orderer_list(cluster_id, block_number).await?;
+        let latest_registered_list = match latest_block_number == block_number {
+            true => registered_list.clone(),
+            false => {
+                self.get_tx_orderer_list(cluster_id, latest_block_number)
+                    .await?
+            }
+        };
+
+        for tx_orderer_address in tx_orderer_address_list {
+            if registered_list.contains(tx_orderer_address) {
+                self.update_draining(
+                    cluster_id,
+                    tx_orderer_address,
+                    !latest_registered_list.contains(tx_orderer_address),
+                )?;
+                continue;
+            }
+
+            let Ok(tx_orderer_rpc_info) = TxOrdererRpcInfo::get(
+                self.platform(),
+                self.liveness_service_provider(),
+                cluster_id,
+                tx_orderer_address,
+            ) else {
+                continue;
+            };
+
+            tracing::info!(
+                "Tx_orderer left the cluster on chain - cluster_id: {:?}, address: {:?}",
+                cluster_id,
+                tx_orderer_address.as_hex_string()
+            );
+
+            TxOrdererRpcInfo::deregister(
+                self.platform(),
+                self.liveness_service_provider(),
+                cluster_id,
+                tx_orderer_address,
+            )?;
+
+            TxOrdererHistoryEntry::from_rpc_info(
+                TxOrdererAction::LeaveCluster,
+                &tx_orderer_rpc_info,
+            )
+            .with_block_number(block_number)
+            .append(tx_orderer_address)?;
+
+            removed.push(tx_orderer_rpc_info);
+        }
+
+        Ok(removed)
+    }
+
+    /// Remember a cluster membership change made by a contract event so that
+    /// the registry entry is checked on every block until the change is
+    /// finalized. Changes of addresses without a registry entry in the cluster
+    /// are ignored.
+    pub async fn track_membership_change(
+        &self,
+        cluster_id: &str,
+        tx_orderer_address: impl AsRef<str>,
+        block_number: Option<u64>,
+    ) -> Result<(), Error> {
+        let tx_orderer_address = normalize_address(tx_orderer_address);
+        let tx_orderer_list = ClusterTxOrdererList::get_or(
+            self.platform(),
+            self.liveness_service_provider(),
+            cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+        let Some(tx_orderer_address) = tx_orderer_list
+            .iter()
+            .find(|address| normalize_address(address.as_hex_string()) == tx_orderer_address)
+        else {
+            return Ok(());
+        };
+
+        let block_number = match block_number {
+            Some(block_number) => block_number,
+            None => self.get_block_number().await?,
+        };
+
+        tracing::info!(
+            "Cluster membership changed on chain - cluster_id: {:?}, address: {:?}, block_number: {:?}",
+            cluster_id,
+            tx_orderer_address.as_hex_string(),
+            block_number
+        );
+
+        let _guard = self.inner.block_processing_lock.lock().await;
+
+        let mut checkpoint = LivenessCheckpoint::get_or(
+            self.platform(),
+            self.liveness_service_provider(),
+            LivenessCheckpoint::default,
+        )?;
+        checkpoint.track(MembershipChange {
+            cluster_id: cluster_id.to_owned(),
+            tx_orderer_address: tx_orderer_address.clone(),
+            block_number,
+        });
+        LivenessCheckpoint::put(
+            &checkpoint,
+            self.platform(),
+            self.liveness_service_provider(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_draining(
+        &self,
+        cluster_id: &str,
+        tx_orderer_address: &Address,
+        is_draining: bool,
+    ) -> Result<(), Error> {
+        let Ok(mut tx_orderer_rpc_info) = TxOrdererRpcInfo::get_mut(
+            self.platform(),
+            self.liveness_service_provider(),
+            cluster_id,
+            tx_orderer_address,
+        ) else {
+            return Ok(());
+        };
+
+        let status = match (is_draining, tx_orderer_rpc_info.status()) {
+            (true, _) => TxOrdererStatus::Draining,
+            // Rejoined the cluster before the exit was finalized.
+            (false, TxOrdererStatus::Draining) => TxOrdererStatus::Active,
+            (false, status) => status,
+        };
+
+        if status != tx_orderer_rpc_info.status() {
+            tracing::info!(
+                "Tx_orderer status changed to {:?} - cluster_id: {:?}, address: {:?}",
+                status,
+                cluster_id,
+                tx_orderer_address.as_hex_string()
+            );
+
+            tx_orderer_rpc_info.set_status(status);
+            tx_orderer_rpc_info.update()?;
+            RegistryChange::upsert(&tx_orderer_rpc_info)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl super::LivenessClient for LivenessClient {
+    fn platform(&self) -> Platform {
+        self.inner.platform
+    }
+
+    fn liveness_service_provider(&self) -> LivenessServiceProvider {
+        self.inner.liveness_service_provider
+    }
+
+    fn finalized_block(&self) -> BoxFuture<'_, Result<u64, Error>> {
+        Box::pin(self.get_finalized_block())
+    }
+
+    fn tx_orderer_list<'a>(
+        &'a self,
+        cluster_id: &'a str,
+        block_number: u64,
+    ) -> BoxFuture<'a, Result<ClusterMembership, Error>> {
+        Box::pin(self.get_tx_orderer_list(cluster_id, block_number))
+    }
+
+    fn validate_tx_orderer<'a>(
+        &'a self,
+        tx_orderer_address: &'a Address,
+        block_number: u64,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        Box::pin(self.validate_operator(tx_orderer_address, block_number))
+    }
+
+    fn cache_stats(&self) -> Option<QueryCacheStats> {
+        Some(self.cache().stats())
+    }
+
+    fn endpoint_health_list(&self) -> Vec<EndpointHealth> {
+        let mut endpoint_health_list = self.rpc_endpoints().health_list();
+        endpoint_health_list.extend(self.websocket_endpoints().health_list());
+
+        endpoint_health_list
+    }
+}
+
+async fn callback(event: Events, liveness_client: LivenessClient) {
+    let result = match event {
+        // Registry entries with pending membership changes are checked on
+        // every block until the change is finalized.
+        Events::Block(_block) => {
+            liveness_client.cache().invalidate_block_number();
+            liveness_client.process_latest_block(None).await
+        }
+        Events::LivenessEvents(LivenessEvents::RegisterTxOrderer(event), log) => {
+            liveness_client
+                .track_membership_change(
+                    &event.clusterId,
+                    event.txOrdererAddress.to_string(),
+                    log.block_number,
+                )
+                .await
+        }
+        Events::LivenessEvents(LivenessEvents::DeregisterTxOrderer(event), log) => {
+            liveness_client
+                .track_membership_change(
+                    &event.clusterId,
+                    event.txOrdererAddress.to_string(),
+                    log.block_number,
+                )
+                .await
+        }
+        Events::LivenessEvents(..) => Ok(()),
+    };
+
+    if let Err(error) = result {
+        tracing::error!(
+            "Failed to process the liveness event for {:?}, {:?}: {:?}",
+            liveness_client.platform(),
+            liveness_client.liveness_service_provider(),
+            error
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::test_util::{init_database, mock_node, signer};
+
+    const CONTRACT_ADDRESS: &str = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+    const SIGNING_KEY: &str = "0x0101010101010101010101010101010101010101010101010101010101010101";
+
+    /// Chain served by the mock node. Blocks hash to their number unless
+    /// overridden in `hash_list`, and every cluster has `tx_orderer_list` as
+    /// members before `left_at`.
+    #[derive(Default)]
+    struct MockChain {
+        block_number: u64,
+        hash_list: HashMap<u64, String>,
+        tx_orderer_list: Vec<Address>,
+        left_at: Option<u64>,
+        has_logs: bool,
+    }
+
+    impl MockChain {
+        fn hash(&self, block_number: u64) -> String {
+            self.hash_list
+                .get(&block_number)
+                .cloned()
+                .unwrap_or_else(|| format!("{:#x}", block_number))
+        }
+    }
+
+    /// ABI encoding of the `address[]` returned by `getTxOrdererList`.
+    fn encode_address_list(address_list: &[Address]) -> String {
+        let mut output = format!("0x{:064x}{:064x}", 32, address_list.len());
+        for address in address_list {
+            output.push_str(&format!(
+                "{:0>64}",
+                address.as_hex_string().trim_start_matches("0x")
+            ));
+        }
+
+        output
+    }
+
+    async fn liveness_client(platform: Platform, chain: Arc<Mutex<MockChain>>) -> LivenessClient {
+        init_database();
+
+        let chain_id = platform.chain_id().unwrap_or_default();
+        let rpc_url = mock_node(move |method, params| {
+            let chain = chain.lock().unwrap();
+
+            match method {
+                "eth_chainId" => Some(json!(format!("{:#x}", chain_id))),
+                "eth_blockNumber" => Some(json!(format!("{:#x}", chain.block_number))),
+                "eth_getBlockByNumber" => {
+                    let block_number =
+                        u64::from_str_radix(params[0].as_str()?.trim_start_matches("0x"), 16)
+                            .ok()?;
+
+                    Some(json!({
+                        "number": format!("{:#x}", block_number),
+                        "hash": chain.hash(block_number),
+                        "parentHash": chain.hash(block_number.saturating_sub(1)),
+                        "stateRoot": format!("0x{:064x}", 0),
+                    }))
+                }
+                "eth_getLogs" => match chain.has_logs {
+                    true => Some(json!([{ "address": CONTRACT_ADDRESS }])),
+                    false => Some(json!([])),
+                },
+                "eth_call" => {
+                    let block_number =
+                        u64::from_str_radix(params[1].as_str()?.trim_start_matches("0x"), 16)
+                            .ok()?;
+
+                    match chain.left_at.is_some_and(|left_at| block_number >= left_at) {
+                        true => Some(json!(encode_address_list(&[]))),
+                        false => Some(json!(encode_address_list(&chain.tx_orderer_list))),
+                    }
+                }
+                _ => None,
+            }
+        })
+        .await;
+
+        let liveness_info = LivenessRadius {
+            liveness_rpc_url: rpc_url,
+            liveness_websocket_url: "ws://127.0.0.1:1".to_owned(),
+            contract_address: CONTRACT_ADDRESS.to_owned(),
+            liveness_rpc_url_list: Vec::new(),
+            liveness_websocket_url_list: Vec::new(),
+            quorum: None,
+            tx_orderer_list_slot: None,
+            block_time: None,
+            validation_info: None,
+        };
+
+        LivenessClient::new(
+            platform,
+            LivenessServiceProvider::Radius,
+            liveness_info,
+            SIGNING_KEY,
+            FinalityPolicy::Confirmations(6),
+        )
+        .unwrap()
+    }
+
+    fn tx_orderer_rpc_info(platform: Platform, cluster_id: &str, seed: u8) -> TxOrdererRpcInfo {
+        TxOrdererRpcInfo::new(
+            platform,
+            LivenessServiceProvider::Radius,
+            cluster_id.to_owned(),
+            signer(seed).address().clone(),
+            "http://127.0.0.1:7000".to_owned(),
+            "http://127.0.0.1:7001".to_owned(),
+            0,
+        )
+    }
+
+    fn is_registered(tx_orderer_rpc_info: &TxOrdererRpcInfo) -> bool {
+        TxOrdererRpcInfo::get(
+            tx_orderer_rpc_info.platform(),
+            tx_orderer_rpc_info.liveness_service_provider(),
+            tx_orderer_rpc_info.cluster_id(),
+            tx_orderer_rpc_info.tx_orderer_address(),
+        )
+        .is_ok()
+    }
+
+    fn checkpoint(platform: Platform) -> LivenessCheckpoint {
+        LivenessCheckpoint::get(platform, LivenessServiceProvider::Radius).unwrap()
+    }
+
+    #[tokio::test]
+    async fn rollback_of_finalized_block_is_saved_without_new_block() {
+        let platform = Platform::Evm(31_001);
+        let removed = tx_orderer_rpc_info(platform, "rollback_finalized_block", 0x51);
+
+        let mut checkpoint = LivenessCheckpoint::default();
+        checkpoint.push(ProcessedBlock {
+            block_number: 5,
+            block_hash: "0x5".to_owned(),
+            removed: vec![removed.clone()],
+        });
+        checkpoint.set_latest_block(LatestBlock {
+            block_number: 11,
+            block_hash: "0xb".to_owned(),
+            finalized_block_number: 5,
+        });
+        LivenessCheckpoint::put(&checkpoint, platform, LivenessServiceProvider::Radius).unwrap();
+
+        // The finalized block was reorganized and the tx_orderer is still in
+        // the cluster on the new chain, while the latest block did not change.
+        let chain = Arc::new(Mutex::new(MockChain {
+            block_number: 11,
+            hash_list: HashMap::from([(5, "0x5a".to_owned())]),
+            tx_orderer_list: vec![removed.tx_orderer_address().clone()],
+            ..Default::default()
+        }));
+        let liveness_client = liveness_client(platform, chain).await;
+
+        liveness_client.process_latest_block(None).await.unwrap();
+
+        assert!(is_registered(&removed));
+        let checkpoint = self::checkpoint(platform);
+        assert!(checkpoint.latest().is_none());
+        assert_eq!(checkpoint.block_number(), Some(11));
+    }
+
+    #[tokio::test]
+    async fn reorg_of_latest_block_keeps_finalized_removals() {
+        let platform = Platform::Evm(31_002);
+        let removed = tx_orderer_rpc_info(platform, "reorg_latest_block", 0x52);
+
+        let mut checkpoint = LivenessCheckpoint::default();
+        checkpoint.push(ProcessedBlock {
+            block_number: 4,
+            block_hash: "0x4".to_owned(),
+            removed: vec![removed.clone()],
+        });
+        checkpoint.set_latest_block(LatestBlock {
+            block_number: 10,
+            block_hash: "0xa0".to_owned(),
+            finalized_block_number: 4,
+        });
+        LivenessCheckpoint::put(&checkpoint, platform, LivenessServiceProvider::Radius).unwrap();
+
+        // Only the latest block was reorganized.
+        let chain = Arc::new(Mutex::new(MockChain {
+            block_number: 11,
+            ..Default::default()
+        }));
+        let liveness_client = liveness_client(platform, chain).await;
+
+        liveness_client.process_latest_block(None).await.unwrap();
+
+        assert!(!is_registered(&removed));
+        let checkpoint = self::checkpoint(platform);
+        assert_eq!(checkpoint.latest().map(|block| block.block_number), Some(4));
+        assert_eq!(
+            checkpoint.latest_block(),
+            Some(&LatestBlock {
+                block_number: 11,
+                block_hash: "0xb".to_owned(),
+                finalized_block_number: 5,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn backfill_diffs_registry_only_after_contract_logs() {
+        let platform = Platform::Evm(31_003);
+        let registered = tx_orderer_rpc_info(platform, "backfill_contract_logs", 0x53);
+        registered.register().unwrap();
+
+        let mut checkpoint = LivenessCheckpoint::default();
+        checkpoint.set_latest_block(LatestBlock {
+            block_number: 8,
+            block_hash: "0x8".to_owned(),
+            finalized_block_number: 2,
+        });
+        LivenessCheckpoint::put(&checkpoint, platform, LivenessServiceProvider::Radius).unwrap();
+
+        // The tx_orderer left the cluster, but the contract has no logs since
+        // the checkpoint, so the registry is not diffed.
+        let chain = Arc::new(Mutex::new(MockChain {
+            block_number: 10,
+            ..Default::default()
+        }));
+        let liveness_client = liveness_client(platform, chain.clone()).await;
+
+        liveness_client.backfill().await.unwrap();
+        assert!(is_registered(&registered));
+        assert_eq!(self::checkpoint(platform).block_number(), Some(10));
+
+        {
+            let mut chain = chain.lock().unwrap();
+            chain.block_number = 12;
+            chain.has_logs = true;
+        }
+        liveness_client.backfill().await.unwrap();
+
+        assert!(!is_registered(&registered));
+        let checkpoint = self::checkpoint(platform);
+        assert_eq!(checkpoint.latest().map(|block| block.block_number), Some(6));
+        assert_eq!(checkpoint.block_number(), Some(12));
+    }
+
+    #[tokio::test]
+    async fn membership_event_only_checks_its_tx_orderer() {
+        let platform = Platform::Evm(31_004);
+        let tracked = tx_orderer_rpc_info(platform, "membership_event_tracked", 0x54);
+        let untracked = tx_orderer_rpc_info(platform, "membership_event_untracked", 0x55);
+        tracked.register().unwrap();
+        untracked.register().unwrap();
+
+        let mut checkpoint = LivenessCheckpoint::default();
+        checkpoint.set_latest_block(LatestBlock {
+            block_number: 11,
+            block_hash: "0xb".to_owned(),
+            finalized_block_number: 5,
+        });
+        LivenessCheckpoint::put(&checkpoint, platform, LivenessServiceProvider::Radius).unwrap();
+
+        // Both tx_orderers left their clusters at block 9, but only the event
+        // of the first one was seen.
+        let chain = Arc::new(Mutex::new(MockChain {
+            block_number: 12,
+            tx_orderer_list: vec![
+                tracked.tx_orderer_address().clone(),
+                untracked.tx_orderer_address().clone(),
+            ],
+            left_at: Some(9),
+            ..Default::default()
+        }));
+        let liveness_client = liveness_client(platform, chain.clone()).await;
+
+        liveness_client
+            .track_membership_change(
+                tracked.cluster_id(),
+                tracked.tx_orderer_address().as_hex_string(),
+                Some(9),
+            )
+            .await
+            .unwrap();
+
+        // The exit is not finalized yet.
+        liveness_client.process_latest_block(None).await.unwrap();
+        let status = |tx_orderer_rpc_info: &TxOrdererRpcInfo| {
+            TxOrdererRpcInfo::get(
+                platform,
+                LivenessServiceProvider::Radius,
+                tx_orderer_rpc_info.cluster_id(),
+                tx_orderer_rpc_info.tx_orderer_address(),
+            )
+            .map(|tx_orderer_rpc_info| tx_orderer_rpc_info.status())
+            .ok()
+        };
+        assert_eq!(status(&tracked), Some(TxOrdererStatus::Draining));
+        assert_eq!(status(&untracked), Some(TxOrdererStatus::Active));
+        assert_eq!(self::checkpoint(platform).pending_change_list().len(), 1);
+
+        chain.lock().unwrap().block_number = 15;
+        liveness_client.process_latest_block(None).await.unwrap();
+
+        assert!(!is_registered(&tracked));
+        assert_eq!(status(&untracked), Some(TxOrdererStatus::Active));
+        let checkpoint = self::checkpoint(platform);
+        assert!(checkpoint.pending_change_list().is_empty());
+        assert_eq!(checkpoint.latest().map(|block| block.block_number), Some(9));
+    }
+
+    #[tokio::test]
+    async fn reject_storage_proofs_without_quorum() {
+        let liveness_info =
+            |liveness_rpc_url_list: Vec<String>, quorum: Option<usize>| LivenessRadius {
+                liveness_rpc_url: "http://127.0.0.1:1".to_owned(),
+                liveness_websocket_url: "ws://127.0.0.1:1".to_owned(),
+                contract_address: CONTRACT_ADDRESS.to_owned(),
+                liveness_rpc_url_list,
+                liveness_websocket_url_list: Vec::new(),
+                quorum,
+                tx_orderer_list_slot: Some(0),
+                block_time: None,
+                validation_info: None,
+            };
+        let new = |liveness_info: LivenessRadius| {
+            LivenessClient::new(
+                Platform::Evm(31_005),
+                LivenessServiceProvider::Radius,
+                liveness_info,
+                SIGNING_KEY,
+                FinalityPolicy::Confirmations(6),
+            )
+        };
+        let backup_url_list = vec!["http://127.0.0.1:2".to_owned()];
+
+        assert!(matches!(
+            new(liveness_info(backup_url_list.clone(), None)),
+            Err(Error::UntrustedStateRoot)
+        ));
+        assert!(matches!(
+            new(liveness_info(Vec::new(), Some(1))),
+            Err(Error::UntrustedStateRoot)
+        ));
+        assert!(new(liveness_info(backup_url_list, Some(2))).is_ok());
+    }
+
+    #[tokio::test]
+    async fn block_number_is_highest_reached_by_quorum() {
+        let platform = Platform::Evm(31_006);
+
+        let mut rpc_url_list = Vec::new();
+        for block_number in [10u64, 12, 11] {
+            let rpc_url = mock_node(move |method, _| match method {
+                "eth_chainId" => Some(json!(format!("{:#x}", 31_006))),
+                "eth_blockNumber" => Some(json!(format!("{:#x}", block_number))),
+                _ => None,
+            })
+            .await;
+            rpc_url_list.push(rpc_url);
+        }
+
+        let liveness_info = LivenessRadius {
+            liveness_rpc_url: rpc_url_list.remove(0),
+            liveness_websocket_url: "ws://127.0.0.1:1".to_owned(),
+            contract_address: CONTRACT_ADDRESS.to_owned(),
+            liveness_rpc_url_list: rpc_url_list,
+            liveness_websocket_url_list: Vec::new(),
+            quorum: Some(2),
+            tx_orderer_list_slot: None,
+            block_time: None,
+            validation_info: None,
+        };
+        let liveness_client = LivenessClient::new(
+            platform,
+            LivenessServiceProvider::Radius,
+            liveness_info,
+            SIGNING_KEY,
+            FinalityPolicy::Latest,
+        )
+        .unwrap();
+
+        // 12 is only reached by one endpoint, 11 by two of them.
+        assert_eq!(liveness_client.fetch_block_number().await.unwrap(), 11);
+    }
+
+    #[tokio::test]
+    async fn reject_quorum_of_half_the_endpoints() {
+        let liveness_info = LivenessRadius {
+            liveness_rpc_url: "http://127.0.0.1:1".to_owned(),
+            liveness_websocket_url: "ws://127.0.0.1:1".to_owned(),
+            contract_address: CONTRACT_ADDRESS.to_owned(),
+            liveness_rpc_url_list: vec!["http://127.0.0.1:2".to_owned()],
+            liveness_websocket_url_list: Vec::new(),
+            quorum: Some(1),
+            tx_orderer_list_slot: None,
+            block_time: None,
+            validation_info: None,
+        };
+
+        assert!(matches!(
+            LivenessClient::new(
+                Platform::Evm(31_007),
+                LivenessServiceProvider::Radius,
+                liveness_info,
+                SIGNING_KEY,
+                FinalityPolicy::Latest,
+            ),
+            Err(Error::InvalidQuorum(1, 2))
+        ));
     }
 }
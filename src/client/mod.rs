@@ -1 +1,4 @@
+pub mod abi;
+pub mod ethereum_rpc;
 pub mod liveness;
+pub mod storage_proof;
@@ -0,0 +1,618 @@
+//! Verification of `eth_getProof` responses against a trusted state root, so
+//! that contract storage can be read without trusting the RPC endpoint.
+
+use serde::Deserialize;
+use sha3::{Digest, Keccak256};
+
+use crate::error::Error;
+
+pub type Word = [u8; 32];
+
+/// Root of a trie without entries, `keccak256(rlp(""))`. Nodes return an empty
+/// proof for it.
+const EMPTY_TRIE_ROOT: Word = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    pub address: String,
+    pub account_proof: Vec<String>,
+    pub storage_hash: String,
+    pub storage_proof: Vec<StorageProof>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StorageProof {
+    pub key: String,
+    pub value: String,
+    pub proof: Vec<String>,
+}
+
+impl AccountProof {
+    /// Verify the account against the state root and return its storage root.
+    pub fn verify(&self, state_root: &Word, address: &str) -> Result<Word, Error> {
+        let address_bytes = decode_hex(address)?;
+        if address_bytes != decode_hex(&self.address)? {
+            return Err(Error::InvalidStorageProof("account address mismatch"));
+        }
+
+        let account_proof = decode_hex_list(&self.account_proof)?;
+        let account = verify_proof(state_root, &keccak256(&address_bytes), &account_proof)?
+            .ok_or(Error::InvalidStorageProof("account does not exist"))?;
+
+        // [nonce, balance, storage_root, code_hash]
+        let account = decode_rlp(&account)?;
+        let storage_root = match account.list_items()? {
+            [_, _, storage_root, _] => to_word(storage_root.bytes()?)?,
+            _ => return Err(Error::InvalidStorageProof("invalid account")),
+        };
+
+        if storage_root != to_word(&decode_hex(&self.storage_hash)?)? {
+            return Err(Error::InvalidStorageProof("storage hash mismatch"));
+        }
+
+        Ok(storage_root)
+    }
+}
+
+impl StorageProof {
+    /// Verify the slot against the storage root and return its value.
+    pub fn verify(&self, storage_root: &Word, slot: &Word) -> Result<Word, Error> {
+        if to_word(&decode_hex(&self.key)?)? != *slot {
+            return Err(Error::InvalidStorageProof("storage key mismatch"));
+        }
+
+        let proof = decode_hex_list(&self.proof)?;
+        let value = match verify_proof(storage_root, &keccak256(slot), &proof)? {
+            Some(value) => to_word(decode_rlp(&value)?.bytes()?)?,
+            None => Word::default(),
+        };
+
+        if value != to_word(&decode_hex(&self.value)?)? {
+            return Err(Error::InvalidStorageProof("storage value mismatch"));
+        }
+
+        Ok(value)
+    }
+}
+
+pub fn keccak256(data: &[u8]) -> Word {
+    Keccak256::digest(data).into()
+}
+
+/// Slot of `mapping[key]` for a mapping stored at `slot`, with `key` encoded
+/// the way Solidity hashes it (raw bytes for `string` and `bytes` keys).
+pub fn mapping_slot(key: &[u8], slot: &Word) -> Word {
+    keccak256(&[key, slot.as_slice()].concat())
+}
+
+/// Slot of the element at `index` of a dynamic array stored at `slot`.
+pub fn array_element_slot(slot: &Word, index: u64) -> Word {
+    let mut element_slot = keccak256(slot);
+
+    let mut carry = index as u128;
+    for byte in element_slot.iter_mut().rev() {
+        if carry == 0 {
+            break;
+        }
+
+        let sum = *byte as u128 + (carry & 0xff);
+        *byte = sum as u8;
+        carry = (carry >> 8) + (sum >> 8);
+    }
+
+    element_slot
+}
+
+pub fn u64_to_word(value: u64) -> Word {
+    let mut word = Word::default();
+    word[24..].copy_from_slice(&value.to_be_bytes());
+
+    word
+}
+
+/// The word as a `u64`, failing if it does not fit.
+pub fn word_to_u64(word: &Word) -> Result<u64, Error> {
+    if word[..24].iter().any(|byte| *byte != 0) {
+        return Err(Error::InvalidStorageProof("value out of range"));
+    }
+
+    Ok(u64::from_be_bytes(word[24..].try_into().unwrap()))
+}
+
+/// The address stored in the low 20 bytes of the word, as lowercase hex.
+pub fn word_to_address(word: &Word) -> String {
+    encode_hex(&word[12..])
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    // Slicing below works on bytes, which would split multi-byte characters.
+    if !hex.is_ascii() {
+        return Err(Error::InvalidStorageProof("invalid hex"));
+    }
+
+    let hex = hex.trim_start_matches("0x");
+    let hex = if hex.len() % 2 == 1 {
+        format!("0{}", hex)
+    } else {
+        hex.to_owned()
+    };
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| {
+            u8::from_str_radix(&hex[index..index + 2], 16)
+                .map_err(|_| Error::InvalidStorageProof("invalid hex"))
+        })
+        .collect()
+}
+
+fn decode_hex_list(hex_list: &[String]) -> Result<Vec<Vec<u8>>, Error> {
+    hex_list.iter().map(|hex| decode_hex(hex)).collect()
+}
+
+/// Left-pad big-endian bytes of at most 32 bytes to a word.
+fn to_word(bytes: &[u8]) -> Result<Word, Error> {
+    if bytes.len() > 32 {
+        return Err(Error::InvalidStorageProof("word longer than 32 bytes"));
+    }
+
+    let mut word = Word::default();
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(word)
+}
+
+/// RLP encoding of a list of byte strings.
+pub fn encode_rlp_bytes_list(item_list: &[Vec<u8>]) -> Vec<u8> {
+    let payload: Vec<u8> = item_list
+        .iter()
+        .flat_map(|item| encode_rlp_bytes(item))
+        .collect();
+
+    [encode_rlp_length(payload.len(), 0xc0), payload].concat()
+}
+
+pub fn encode_rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    match bytes {
+        [byte] if *byte < 0x80 => vec![*byte],
+        _ => [encode_rlp_length(bytes.len(), 0x80), bytes.to_vec()].concat(),
+    }
+}
+
+fn encode_rlp_length(length: usize, offset: u8) -> Vec<u8> {
+    if length <= 55 {
+        return vec![offset + length as u8];
+    }
+
+    let length_bytes: Vec<u8> = length
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|byte| *byte == 0)
+        .collect();
+
+    [vec![offset + 55 + length_bytes.len() as u8], length_bytes].concat()
+}
+
+enum RlpItem<'a> {
+    Bytes(&'a [u8]),
+    /// The encoded list, kept for nodes embedded in their parent.
+    List(&'a [u8], Vec<RlpItem<'a>>),
+}
+
+impl<'a> RlpItem<'a> {
+    fn bytes(&self) -> Result<&'a [u8], Error> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            Self::List(..) => Err(Error::InvalidStorageProof("expected RLP bytes")),
+        }
+    }
+
+    fn list_items(&self) -> Result<&[RlpItem<'a>], Error> {
+        match self {
+            Self::List(_, items) => Ok(items),
+            Self::Bytes(_) => Err(Error::InvalidStorageProof("expected RLP list")),
+        }
+    }
+}
+
+fn decode_rlp(data: &[u8]) -> Result<RlpItem<'_>, Error> {
+    let (item, rest) = decode_rlp_item(data)?;
+    if !rest.is_empty() {
+        return Err(Error::InvalidStorageProof("trailing RLP data"));
+    }
+
+    Ok(item)
+}
+
+fn decode_rlp_item(data: &[u8]) -> Result<(RlpItem<'_>, &[u8]), Error> {
+    let invalid = || Error::InvalidStorageProof("invalid RLP");
+    let prefix = *data.first().ok_or_else(invalid)?;
+
+    let (is_list, offset, length) = match prefix {
+        0x00..=0x7f => return Ok((RlpItem::Bytes(&data[..1]), &data[1..])),
+        0x80..=0xb7 => (false, 1, (prefix - 0x80) as usize),
+        0xb8..=0xbf => {
+            let length_of_length = (prefix - 0xb7) as usize;
+            (
+                false,
+                1 + length_of_length,
+                decode_length(data.get(1..1 + length_of_length).ok_or_else(invalid)?)?,
+            )
+        }
+        0xc0..=0xf7 => (true, 1, (prefix - 0xc0) as usize),
+        0xf8..=0xff => {
+            let length_of_length = (prefix - 0xf7) as usize;
+            (
+                true,
+                1 + length_of_length,
+                decode_length(data.get(1..1 + length_of_length).ok_or_else(invalid)?)?,
+            )
+        }
+    };
+
+    let end = offset.checked_add(length).ok_or_else(invalid)?;
+    let payload = data.get(offset..end).ok_or_else(invalid)?;
+    let rest = &data[end..];
+
+    if !is_list {
+        return Ok((RlpItem::Bytes(payload), rest));
+    }
+
+    let mut items = Vec::new();
+    let mut remaining = payload;
+    while !remaining.is_empty() {
+        let (item, next) = decode_rlp_item(remaining)?;
+        items.push(item);
+        remaining = next;
+    }
+
+    Ok((RlpItem::List(&data[..end], items), rest))
+}
+
+fn decode_length(bytes: &[u8]) -> Result<usize, Error> {
+    if bytes.len() > std::mem::size_of::<usize>() {
+        return Err(Error::InvalidStorageProof("invalid RLP length"));
+    }
+
+    Ok(bytes
+        .iter()
+        .fold(0, |length, byte| (length << 8) | *byte as usize))
+}
+
+enum NodeReference {
+    Hash(Word),
+    Inline(Vec<u8>),
+}
+
+fn node_reference(item: &RlpItem) -> Result<Option<NodeReference>, Error> {
+    match item {
+        RlpItem::Bytes([]) => Ok(None),
+        RlpItem::Bytes(hash) if hash.len() == 32 => Ok(Some(NodeReference::Hash(to_word(hash)?))),
+        RlpItem::List(encoded, _) => Ok(Some(NodeReference::Inline(encoded.to_vec()))),
+        RlpItem::Bytes(_) => Err(Error::InvalidStorageProof("invalid node reference")),
+    }
+}
+
+/// Nibbles of a hex-prefix encoded path and whether it ends in a leaf.
+fn decode_path(path: &[u8]) -> Result<(bool, Vec<u8>), Error> {
+    let first = *path
+        .first()
+        .ok_or(Error::InvalidStorageProof("empty node path"))?;
+    let flag = first >> 4;
+
+    let mut nibbles = Vec::with_capacity(path.len() * 2);
+    if flag & 1 == 1 {
+        nibbles.push(first & 0x0f);
+    }
+    for byte in &path[1..] {
+        nibbles.push(byte >> 4);
+        nibbles.push(byte & 0x0f);
+    }
+
+    match flag {
+        0 | 1 => Ok((false, nibbles)),
+        2 | 3 => Ok((true, nibbles)),
+        _ => Err(Error::InvalidStorageProof("invalid node path")),
+    }
+}
+
+/// Walk a Merkle Patricia Trie proof from `root` along `key`. Returns the value
+/// stored under the key, or `None` if the proof shows that it is absent.
+fn verify_proof(root: &Word, key: &[u8], proof: &[Vec<u8>]) -> Result<Option<Vec<u8>>, Error> {
+    let key_nibbles: Vec<u8> = key
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect();
+    let mut nibbles = key_nibbles.as_slice();
+
+    if *root == EMPTY_TRIE_ROOT {
+        return Ok(None);
+    }
+
+    let mut proof = proof.iter();
+    let mut reference = NodeReference::Hash(*root);
+
+    loop {
+        let node = match reference {
+            NodeReference::Hash(hash) => {
+                let node = proof
+                    .next()
+                    .ok_or(Error::InvalidStorageProof("missing proof node"))?;
+                if keccak256(node) != hash {
+                    return Err(Error::InvalidStorageProof("proof node hash mismatch"));
+                }
+
+                node.clone()
+            }
+            NodeReference::Inline(node) => node,
+        };
+
+        let node = decode_rlp(&node)?;
+        let next = match node.list_items()? {
+            [branch @ .., value] if branch.len() == 16 => {
+                let Some((nibble, rest)) = nibbles.split_first() else {
+                    let value = value.bytes()?;
+                    return Ok((!value.is_empty()).then(|| value.to_vec()));
+                };
+                nibbles = rest;
+
+                node_reference(&branch[*nibble as usize])?
+            }
+            [path, child] => {
+                let (is_leaf, path) = decode_path(path.bytes()?)?;
+                if is_leaf {
+                    if nibbles != path.as_slice() {
+                        return Ok(None);
+                    }
+
+                    return Ok(Some(child.bytes()?.to_vec()));
+                }
+
+                if !nibbles.starts_with(&path) {
+                    return Ok(None);
+                }
+                nibbles = &nibbles[path.len()..];
+
+                node_reference(child)?
+            }
+            _ => return Err(Error::InvalidStorageProof("invalid trie node")),
+        };
+
+        match next {
+            Some(next) => reference = next,
+            None => return Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Root of the trie {"do": "verb", "dog": "puppy", "doge": "coin",
+    /// "horse": "stallion"} from the Ethereum trie tests.
+    const DOGE_TRIE_ROOT: &str =
+        "0x5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84";
+
+    const DOGE_TRIE_PROOF: [&str; 4] = [
+        "0xe216a0bd3ee507e6c67cfefca98f84be47c1bbc009315fabc4405db4ba32190374572a",
+        "0xf84080808080a094a9f95bd89698e4da1812e0518053813b4d5b87caaf6b3c6fa57e9e50c0ff68808080cf85206f727365887374616c6c696f6e8080808080808080",
+        "0xe482006fa0d43b87fdcd4217013ccc92d04662e12d36e4cc25dc690077cd821a1956fc3e36",
+        "0xf3808080808080de17dc808080808080c63584636f696e8080808080808080808570757070798080808080808080808476657262",
+    ];
+
+    /// Account 0x5fbd..0aa3 with nonce 1 and storage {0: 0x2a, 1: 0x0100}, in
+    /// a state trie with one other account.
+    const STATE_ROOT: &str = "0xc44da81b2e13fcd15c649d5757390cc1c8c7bbc49f2c871637b14ca9f74d07bf";
+    const CONTRACT_ADDRESS: &str = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+    const STORAGE_ROOT: &str = "0xa04717bb1ad749fb37a7a1bf15aad9029b8eaccd713db2505872f257c94a6ff1";
+    const ACCOUNT_PROOF: [&str; 2] = [
+        "0xf85180808080a00380bc61e6e1264cb83fa7864f499769290f2151a41159196cc68ee1450e03328080a02704a8cadf2dd8773c6a12c92bdf45bae1b94be6723b05b35194cf7e0392bb3b808080808080808080",
+        "0xf869a034e659e60b21cc961f64ad47f20523c1d329d4bbda245ef3940a76dc89d0911bb846f8440180a0a04717bb1ad749fb37a7a1bf15aad9029b8eaccd713db2505872f257c94a6ff1a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+    ];
+    const STORAGE_BRANCH: &str = "0xf8518080a0f73cea67884580eec8c3f6d0746360906cf897bf812183520e51b89a12166cfe8080808080808080a0b0dd17c59d83cca7e3f924f0d35a156b7f8d1aa78e0d04dd547d0d807b5e832d8080808080";
+    const SLOT_0_LEAF: &str =
+        "0xe2a0390decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e5632a";
+    const SLOT_1_LEAF: &str =
+        "0xe5a0310e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf683820100";
+
+    fn word(hex: &str) -> Word {
+        to_word(&decode_hex(hex).unwrap()).unwrap()
+    }
+
+    fn hex_list(hex_list: &[&str]) -> Vec<String> {
+        hex_list.iter().map(|hex| hex.to_string()).collect()
+    }
+
+    fn account_proof(storage_proof: Vec<StorageProof>) -> AccountProof {
+        AccountProof {
+            address: CONTRACT_ADDRESS.to_owned(),
+            account_proof: hex_list(&ACCOUNT_PROOF),
+            storage_hash: STORAGE_ROOT.to_owned(),
+            storage_proof,
+        }
+    }
+
+    fn storage_proof(slot: u64, value: &str, proof: &[&str]) -> StorageProof {
+        StorageProof {
+            key: format!("{:#x}", slot),
+            value: value.to_owned(),
+            proof: hex_list(proof),
+        }
+    }
+
+    #[test]
+    fn decode_rlp_vectors() {
+        assert_eq!(
+            decode_rlp(&[0x83, b'd', b'o', b'g'])
+                .unwrap()
+                .bytes()
+                .unwrap(),
+            b"dog"
+        );
+        assert_eq!(decode_rlp(&[0x80]).unwrap().bytes().unwrap(), b"");
+        assert_eq!(decode_rlp(&[0x0f]).unwrap().bytes().unwrap(), [0x0f]);
+
+        let list = decode_hex("0xc88363617483646f67").unwrap();
+        let list = decode_rlp(&list).unwrap();
+        let items = list.list_items().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].bytes().unwrap(), b"cat");
+        assert_eq!(items[1].bytes().unwrap(), b"dog");
+
+        let long_string = [[0xb8, 56].as_slice(), &[b'a'; 56]].concat();
+        assert_eq!(
+            decode_rlp(&long_string).unwrap().bytes().unwrap(),
+            [b'a'; 56]
+        );
+
+        // Truncated payload and trailing bytes.
+        assert!(decode_rlp(&[0x83, b'd', b'o']).is_err());
+        assert!(decode_rlp(&[0x83, b'd', b'o', b'g', 0x00]).is_err());
+        assert!(decode_rlp(&[]).is_err());
+    }
+
+    #[test]
+    fn encode_rlp_vectors() {
+        assert_eq!(encode_rlp_bytes(b"dog"), [0x83, b'd', b'o', b'g']);
+        assert_eq!(encode_rlp_bytes(b""), [0x80]);
+        assert_eq!(encode_rlp_bytes(&[0x0f]), [0x0f]);
+        assert_eq!(encode_rlp_bytes(&[0x80]), [0x81, 0x80]);
+        assert_eq!(
+            encode_rlp_bytes_list(&[b"cat".to_vec(), b"dog".to_vec()]),
+            decode_hex("0xc88363617483646f67").unwrap()
+        );
+        assert_eq!(encode_rlp_bytes_list(&[]), [0xc0]);
+        assert_eq!(
+            encode_rlp_bytes(&[b'a'; 56]),
+            [[0xb8, 56].as_slice(), &[b'a'; 56]].concat()
+        );
+        assert_eq!(encode_rlp_bytes(&[b'a'; 1024])[..3], [0xb9, 0x04, 0x00]);
+    }
+
+    #[test]
+    fn decode_hex_rejects_invalid_input() {
+        assert_eq!(decode_hex("0x0").unwrap(), [0x00]);
+        assert_eq!(decode_hex("0x1234").unwrap(), [0x12, 0x34]);
+        assert!(decode_hex("0xzz").is_err());
+        assert!(decode_hex("0xé0").is_err());
+        assert!(decode_hex("0x0é").is_err());
+    }
+
+    #[test]
+    fn verify_trie_proof() {
+        let root = word(DOGE_TRIE_ROOT);
+        let proof = decode_hex_list(&hex_list(&DOGE_TRIE_PROOF)).unwrap();
+
+        assert_eq!(
+            verify_proof(&root, b"dog", &proof).unwrap(),
+            Some(b"puppy".to_vec())
+        );
+        assert_eq!(
+            verify_proof(&root, b"doge", &proof).unwrap(),
+            Some(b"coin".to_vec())
+        );
+        assert_eq!(
+            verify_proof(&root, b"do", &proof).unwrap(),
+            Some(b"verb".to_vec())
+        );
+        assert_eq!(
+            verify_proof(&root, b"horse", &proof[..2]).unwrap(),
+            Some(b"stallion".to_vec())
+        );
+
+        // Absent keys are proven by the path ending before them.
+        assert_eq!(verify_proof(&root, b"dot", &proof).unwrap(), None);
+        assert_eq!(verify_proof(&root, b"cat", &proof[..2]).unwrap(), None);
+    }
+
+    #[test]
+    fn reject_invalid_trie_proof() {
+        let root = word(DOGE_TRIE_ROOT);
+        let proof = decode_hex_list(&hex_list(&DOGE_TRIE_PROOF)).unwrap();
+
+        // Missing node.
+        assert!(verify_proof(&root, b"dog", &proof[..3]).is_err());
+
+        // Tampered value in the last node.
+        let mut tampered = proof.clone();
+        let last = tampered.last_mut().unwrap();
+        let index = last.len() - 2;
+        last[index] ^= 1;
+        assert!(verify_proof(&root, b"dog", &tampered).is_err());
+
+        // Different root.
+        assert!(verify_proof(&keccak256(b"root"), b"dog", &proof).is_err());
+    }
+
+    #[test]
+    fn empty_trie_proves_absence() {
+        assert_eq!(EMPTY_TRIE_ROOT, keccak256(&[0x80]));
+        assert_eq!(verify_proof(&EMPTY_TRIE_ROOT, b"dog", &[]).unwrap(), None);
+    }
+
+    #[test]
+    fn verify_account_and_storage_proof() {
+        let account_proof = account_proof(vec![
+            storage_proof(0, "0x2a", &[STORAGE_BRANCH, SLOT_0_LEAF]),
+            storage_proof(1, "0x100", &[STORAGE_BRANCH, SLOT_1_LEAF]),
+            storage_proof(2, "0x0", &[STORAGE_BRANCH]),
+        ]);
+
+        let storage_root = account_proof
+            .verify(&word(STATE_ROOT), CONTRACT_ADDRESS)
+            .unwrap();
+        assert_eq!(storage_root, word(STORAGE_ROOT));
+
+        let value_list: Vec<Word> = account_proof
+            .storage_proof
+            .iter()
+            .zip(0..)
+            .map(|(proof, slot)| proof.verify(&storage_root, &u64_to_word(slot)).unwrap())
+            .collect();
+        assert_eq!(
+            value_list,
+            [u64_to_word(0x2a), u64_to_word(0x100), u64_to_word(0)]
+        );
+    }
+
+    #[test]
+    fn reject_forged_storage_value() {
+        let storage_root = word(STORAGE_ROOT);
+
+        // The value reported next to the proof has to match the proven one.
+        let forged = storage_proof(0, "0x2b", &[STORAGE_BRANCH, SLOT_0_LEAF]);
+        assert!(forged.verify(&storage_root, &u64_to_word(0)).is_err());
+
+        // A proof of absence cannot be used for a slot that has a value.
+        let forged = storage_proof(0, "0x0", &[STORAGE_BRANCH]);
+        assert!(forged.verify(&storage_root, &u64_to_word(0)).is_err());
+
+        // The proof of one slot cannot be reused for another.
+        let forged = storage_proof(1, "0x2a", &[STORAGE_BRANCH, SLOT_0_LEAF]);
+        assert!(forged.verify(&storage_root, &u64_to_word(1)).is_err());
+    }
+
+    #[test]
+    fn reject_account_under_other_state_root() {
+        let account_proof = account_proof(Vec::new());
+
+        assert!(account_proof
+            .verify(&keccak256(b"state root"), CONTRACT_ADDRESS)
+            .is_err());
+        assert!(account_proof
+            .verify(
+                &word(STATE_ROOT),
+                "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512"
+            )
+            .is_err());
+    }
+}
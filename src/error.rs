@@ -2,11 +2,55 @@
 pub enum Error {
     Config(crate::types::ConfigError),
     Database(radius_sdk::kvstore::KvStoreError),
+    CachedKvStore(radius_sdk::kvstore::CachedKvStoreError),
     RpcServer(radius_sdk::json_rpc::server::RpcServerError),
     Signature(radius_sdk::signature::SignatureError),
+    InvalidSignature,
+    StaleNonce,
     LivenessClient(Box<dyn std::error::Error>),
+    LivenessClientNotFound(
+        crate::types::Platform,
+        crate::types::LivenessServiceProvider,
+    ),
+    LivenessInfoNotFound(
+        crate::types::Platform,
+        crate::types::LivenessServiceProvider,
+    ),
+    LivenessClientNotReady(
+        crate::types::Platform,
+        crate::types::LivenessServiceProvider,
+        crate::types::LivenessClientStatus,
+    ),
+    EthereumRpc(reqwest::Error),
+    EmptyEndpointList,
+    EndpointTimeout(String),
+    LivenessEventListenerStopped,
+    InvalidQuorum(usize, usize),
+    QuorumNotReached(usize, usize),
+    ConflictingQuorum(usize),
+    InvalidStorageProof(&'static str),
+    UntrustedStateRoot,
+    InvalidBlockHeader(&'static str),
+    TxOrdererListTooLong(u64),
+    ChainIdMismatch(String, u64, u64),
+    InvalidAbiAddress,
+    InvalidAbiOutput,
+    LoadLocalClusterList(std::io::Error),
+    ParseLocalClusterList(serde_json::Error),
+    EmptyLocalClusterList,
+    LivenessInfoPayloadMismatch(crate::types::Platform),
+    EthereumRpcResponse(&'static str, serde_json::Value),
+    InvalidBlockMargin,
     NotRegisteredInContract,
+    TxOrdererNotFound,
     NotDeregisteredFromContract,
+    NotRegisteredInExecutorList,
+    LeaseDisabled,
+    NotRegisteredOperator,
+    NotOptedInToNetwork,
+    InsufficientStake(u128, u128),
+    RegistryChangesPruned(u64),
+    UnsupportedValidationServiceProvider(String),
     UnsupportedPlatform,
     InvalidURL(reqwest::Error),
     HealthCheck(reqwest::Error),
@@ -45,3 +89,9 @@ impl From<radius_sdk::signature::SignatureError> for Error {
         Self::Signature(value)
     }
 }
+
+impl From<radius_sdk::kvstore::CachedKvStoreError> for Error {
+    fn from(value: radius_sdk::kvstore::CachedKvStoreError) -> Self {
+        Self::CachedKvStore(value)
+    }
+}
@@ -2,5 +2,8 @@ pub mod client;
 pub mod error;
 pub mod rpc;
 pub mod state;
+pub mod task;
+#[cfg(test)]
+mod test_util;
 pub mod types;
 pub mod util;
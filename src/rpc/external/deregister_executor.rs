@@ -0,0 +1,126 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeregisterExecutor {
+    message: DeregisterExecutorMessage,
+    signature: Signature,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct DeregisterExecutorMessage {
+    platform: Platform,
+    rollup_id: String,
+    executor_address: Address,
+    nonce: u64,
+}
+
+impl RpcParameter<AppState> for DeregisterExecutor {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "deregister_executor"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        // Verify the message.
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.executor_address,
+            )
+            .map_err(|_| Error::InvalidSignature)?;
+        Nonce::check(&self.message.executor_address, self.message.nonce)?;
+
+        tracing::info!(
+            "Deregister executor - rollup_id: {:?}, address: {:?}",
+            self.message.rollup_id,
+            self.message.executor_address.as_hex_string()
+        );
+
+        // An executor can always remove its own record, also after it was
+        // dropped from the executor list of the rollup.
+        Nonce::commit(&self.message.executor_address, self.message.nonce)?;
+        ExecutorRpcInfo::deregister(&self.message.rollup_id, &self.message.executor_address)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    fn register(rollup_id: &str, executor_address: &Address) {
+        ExecutorAddressList::put(
+            &ExecutorAddressList::new(vec![executor_address.clone()]),
+            rollup_id,
+        )
+        .unwrap();
+        ExecutorRpcInfo::new(
+            rollup_id.to_owned(),
+            executor_address.clone(),
+            "http://127.0.0.1:8000".to_owned(),
+        )
+        .register()
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn deregister_keeps_other_rollups() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let rollup_id_a = "deregister_keeps_other_rollups_a";
+        let rollup_id_b = "deregister_keeps_other_rollups_b";
+        let signer = signer(0x41);
+        register(rollup_id_a, signer.address());
+        register(rollup_id_b, signer.address());
+
+        let message = DeregisterExecutorMessage {
+            platform: PLATFORM,
+            rollup_id: rollup_id_b.to_owned(),
+            executor_address: signer.address().clone(),
+            nonce: 1,
+        };
+        let signature = sign(&context, &signer, DeregisterExecutor::method(), &message).await;
+        let deregister_executor = DeregisterExecutor { message, signature };
+
+        assert!(deregister_executor.handler(context).await.is_ok());
+        assert!(ExecutorRpcInfo::get(rollup_id_a, signer.address()).is_ok());
+        assert!(ExecutorRpcInfo::get(rollup_id_b, signer.address()).is_err());
+
+        let executor_rpc_info_list =
+            ExecutorRpcInfo::get_list_by_address(signer.address()).unwrap();
+        assert_eq!(executor_rpc_info_list.len(), 1);
+        assert_eq!(executor_rpc_info_list[0].rollup_id(), rollup_id_a);
+    }
+
+    #[tokio::test]
+    async fn deregister_after_removal_from_list() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let rollup_id = "deregister_after_removal_from_list";
+        let signer = signer(0x42);
+        register(rollup_id, signer.address());
+        ExecutorAddressList::put(&ExecutorAddressList::default(), rollup_id).unwrap();
+
+        let message = DeregisterExecutorMessage {
+            platform: PLATFORM,
+            rollup_id: rollup_id.to_owned(),
+            executor_address: signer.address().clone(),
+            nonce: 1,
+        };
+        let signature = sign(&context, &signer, DeregisterExecutor::method(), &message).await;
+        let deregister_executor = DeregisterExecutor { message, signature };
+
+        assert!(deregister_executor.handler(context).await.is_ok());
+        assert!(ExecutorRpcInfo::get(rollup_id, signer.address()).is_err());
+        assert!(ExecutorRpcInfo::get_list_by_address(signer.address())
+            .unwrap()
+            .is_empty());
+    }
+}
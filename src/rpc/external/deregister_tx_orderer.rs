@@ -12,6 +12,21 @@ struct DeregisterTxOrdererMessage {
     liveness_service_provider: LivenessServiceProvider,
     cluster_id: String,
     tx_orderer_address: Address,
+    nonce: u64,
+}
+
+impl DeregisterTxOrderer {
+    /// Check that the message is signed by the tx_orderer for this method.
+    async fn verify_signature(&self, context: &AppState) -> Result<(), Error> {
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.tx_orderer_address,
+            )
+            .map_err(|_| Error::InvalidSignature)
+    }
 }
 
 impl RpcParameter<AppState> for DeregisterTxOrderer {
@@ -22,58 +37,224 @@ impl RpcParameter<AppState> for DeregisterTxOrderer {
     }
 
     async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
-        // Verify the message.
-        // self.signature.verify_message(
-        //     self.message.platform.into(),
-        //     &self.message,
-        //     &self.message.address,
-        // )?;
+        self.verify_signature(&context).await?;
+        Nonce::check(&self.message.tx_orderer_address, self.message.nonce)?;
 
         tracing::info!(
             "Deregister tx_orderer: {:?}",
             self.message.tx_orderer_address.as_hex_string()
         );
 
-        match self.message.platform {
-            Platform::Ethereum => {
-                let liveness_client: liveness::radius::LivenessClient = context
-                    .get_liveness_client(
-                        self.message.platform,
-                        self.message.liveness_service_provider,
-                    )
-                    .await?;
-
-                let block_margin = liveness_client
-                    .publisher()
-                    .get_block_margin()
-                    .await
-                    .map_err(|error| Error::LivenessClient(error.into()))?
-                    .try_into()?;
-
-                let block_number = liveness_client
-                    .publisher()
-                    .get_block_number()
-                    .await
-                    .map_err(|error| Error::LivenessClient(error.into()))?
-                    .wrapping_sub(block_margin);
-
-                let tx_orderer_list = liveness_client
-                    .publisher()
-                    .get_tx_orderer_list(&self.message.cluster_id, block_number)
-                    .await
-                    .map_err(|error| Error::LivenessClient(error.into()))?;
-
-                // check if the tx_orderer is deregistered from the contract
-                tx_orderer_list
-                    .iter()
-                    .find(|&&address| self.message.tx_orderer_address == address)
-                    .map_or(Ok(()), |_| Err(Error::NotDeregisteredFromContract))?;
-            }
-            Platform::Local => return Err(Error::UnsupportedPlatform.into()),
+        let liveness_client = context
+            .get_liveness_client(
+                self.message.platform,
+                self.message.liveness_service_provider,
+            )
+            .await?;
+
+        let block_number = liveness_client.finalized_block().await?;
+        let tx_orderer_list = liveness_client
+            .tx_orderer_list(&self.message.cluster_id, block_number)
+            .await?;
+
+        // check if the tx_orderer is deregistered from the contract
+        if tx_orderer_list.contains(&self.message.tx_orderer_address) {
+            return Err(Error::NotDeregisteredFromContract.into());
         }
 
-        TxOrdererRpcInfo::delete(&self.message.tx_orderer_address)?;
+        tracing::info!(
+            "Deregister tx_orderer - address: {:?}, block_number: {:?}",
+            self.message.tx_orderer_address.as_hex_string(),
+            block_number
+        );
+
+        Nonce::commit(&self.message.tx_orderer_address, self.message.nonce)?;
+        TxOrdererRpcInfo::deregister(
+            self.message.platform,
+            self.message.liveness_service_provider,
+            &self.message.cluster_id,
+            &self.message.tx_orderer_address,
+        )?;
+
+        TxOrdererHistoryEntry::new(
+            TxOrdererAction::Deregister,
+            self.message.platform,
+            self.message.liveness_service_provider,
+            &self.message.cluster_id,
+        )
+        .with_block_number(block_number)
+        .append(&self.message.tx_orderer_address)?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    fn message(
+        cluster_id: &str,
+        tx_orderer_address: &Address,
+        nonce: u64,
+    ) -> DeregisterTxOrdererMessage {
+        DeregisterTxOrdererMessage {
+            platform: PLATFORM,
+            liveness_service_provider: LIVENESS_SERVICE_PROVIDER,
+            cluster_id: cluster_id.to_owned(),
+            tx_orderer_address: tx_orderer_address.clone(),
+            nonce,
+        }
+    }
+
+    fn register(cluster_id: &str, tx_orderer_address: &Address) {
+        TxOrdererRpcInfo::new(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id.to_owned(),
+            tx_orderer_address.clone(),
+            "http://127.0.0.1:7000".to_owned(),
+            "http://127.0.0.1:7001".to_owned(),
+            0,
+        )
+        .register()
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn deregister_after_leaving_cluster() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let cluster_id = "deregister_after_leaving_cluster";
+        let signer = signer(0x21);
+        liveness_client.add_tx_orderer(cluster_id, signer.address());
+        register(cluster_id, signer.address());
+
+        // Still a member of the cluster on chain.
+        let message_1 = message(cluster_id, signer.address(), 1);
+        let signature = sign(&context, &signer, DeregisterTxOrderer::method(), &message_1).await;
+        let deregister_tx_orderer = DeregisterTxOrderer {
+            message: message_1,
+            signature,
+        };
+        assert!(deregister_tx_orderer
+            .handler(context.clone())
+            .await
+            .is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_ok());
+
+        liveness_client.remove_tx_orderer(cluster_id, signer.address());
+
+        let message_2 = message(cluster_id, signer.address(), 2);
+        let signature = sign(&context, &signer, DeregisterTxOrderer::method(), &message_2).await;
+        let deregister_tx_orderer = DeregisterTxOrderer {
+            message: message_2,
+            signature,
+        };
+        assert!(deregister_tx_orderer.handler(context).await.is_ok());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_wrong_signer() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let cluster_id = "deregister_reject_wrong_signer";
+        let tx_orderer_signer = signer(0x22);
+        let other_signer = signer(0x23);
+        register(cluster_id, tx_orderer_signer.address());
+
+        let message = message(cluster_id, tx_orderer_signer.address(), 1);
+        let signature = sign(
+            &context,
+            &other_signer,
+            DeregisterTxOrderer::method(),
+            &message,
+        )
+        .await;
+        let deregister_tx_orderer = DeregisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            deregister_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(deregister_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            tx_orderer_signer.address(),
+        )
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_tampered_message() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let cluster_id = "deregister_reject_tampered_message";
+        let signer = signer(0x24);
+        register(cluster_id, signer.address());
+
+        let mut message = message("deregister_other_cluster", signer.address(), 1);
+        let signature = sign(&context, &signer, DeregisterTxOrderer::method(), &message).await;
+        message.cluster_id = cluster_id.to_owned();
+        let deregister_tx_orderer = DeregisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            deregister_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(deregister_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_signature_of_other_method() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let cluster_id = "deregister_reject_signature_of_other_method";
+        let signer = signer(0x25);
+        register(cluster_id, signer.address());
+
+        let message = message(cluster_id, signer.address(), 1);
+        let signature = sign(&context, &signer, "register_tx_orderer", &message).await;
+        let deregister_tx_orderer = DeregisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            deregister_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(deregister_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_ok());
+    }
+}
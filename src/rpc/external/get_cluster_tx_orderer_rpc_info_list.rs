@@ -0,0 +1,66 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetClusterTxOrdererRpcInfoList {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    cluster_id: String,
+    /// Only return tx_orderers that are still registered in the liveness
+    /// contract at the block the finality policy considers final.
+    #[serde(default)]
+    registered_in_contract: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetClusterTxOrdererRpcInfoListResponse {
+    pub tx_orderer_rpc_info_list: Vec<TxOrdererRpcInfo>,
+}
+
+impl RpcParameter<AppState> for GetClusterTxOrdererRpcInfoList {
+    type Response = GetClusterTxOrdererRpcInfoListResponse;
+
+    fn method() -> &'static str {
+        "get_cluster_tx_orderer_rpc_info_list"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        let tx_orderer_list = ClusterTxOrdererList::get_or(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+
+        let mut tx_orderer_rpc_info_list: Vec<TxOrdererRpcInfo> = tx_orderer_list
+            .iter()
+            .filter_map(|tx_orderer_address| {
+                TxOrdererRpcInfo::get(
+                    self.platform,
+                    self.liveness_service_provider,
+                    &self.cluster_id,
+                    tx_orderer_address,
+                )
+                .ok()
+            })
+            .collect();
+
+        if self.registered_in_contract {
+            let liveness_client = context
+                .get_liveness_client(self.platform, self.liveness_service_provider)
+                .await?;
+
+            let block_number = liveness_client.finalized_block().await?;
+            let registered_list = liveness_client
+                .tx_orderer_list(&self.cluster_id, block_number)
+                .await?;
+
+            tx_orderer_rpc_info_list.retain(|tx_orderer_rpc_info| {
+                registered_list.contains(tx_orderer_rpc_info.tx_orderer_address())
+            });
+        }
+
+        Ok(GetClusterTxOrdererRpcInfoListResponse {
+            tx_orderer_rpc_info_list,
+        })
+    }
+}
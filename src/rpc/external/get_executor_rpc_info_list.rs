@@ -3,6 +3,10 @@ use crate::rpc::prelude::*;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetExecutorRpcInfoList {
     executor_address_list: Vec<Address>,
+    /// Only return the records of the rollup. Without it, the records of
+    /// every rollup the executors serve are returned.
+    #[serde(default)]
+    rollup_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -18,11 +22,23 @@ impl RpcParameter<AppState> for GetExecutorRpcInfoList {
     }
 
     async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
-        let executor_rpc_info_list: Vec<ExecutorRpcInfo> = self
-            .executor_address_list
-            .into_iter()
-            .filter_map(|executor_address| ExecutorRpcInfo::get(&executor_address).ok())
-            .collect();
+        let executor_rpc_info_list: Vec<ExecutorRpcInfo> = match &self.rollup_id {
+            Some(rollup_id) => self
+                .executor_address_list
+                .iter()
+                .filter_map(|executor_address| {
+                    ExecutorRpcInfo::get(rollup_id, executor_address).ok()
+                })
+                .collect(),
+            None => self
+                .executor_address_list
+                .iter()
+                .filter_map(|executor_address| {
+                    ExecutorRpcInfo::get_list_by_address(executor_address).ok()
+                })
+                .flatten()
+                .collect(),
+        };
 
         Ok(GetExecutorRpcInfoListResponse {
             executor_rpc_info_list,
@@ -0,0 +1,40 @@
+use crate::rpc::prelude::*;
+
+const DEFAULT_LIMIT: u64 = 100;
+const MAX_LIMIT: u64 = 1000;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetRegistryChanges {
+    /// Sequence number of the last change the client applied, or 0 to read
+    /// the feed from the start.
+    #[serde(default)]
+    since_seq: u64,
+    #[serde(default)]
+    limit: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetRegistryChangesResponse {
+    pub change_list: Vec<RegistryChange>,
+    /// Sequence number of the latest change, so the client knows whether to
+    /// keep paging.
+    pub latest_seq: u64,
+}
+
+impl RpcParameter<AppState> for GetRegistryChanges {
+    type Response = GetRegistryChangesResponse;
+
+    fn method() -> &'static str {
+        "get_registry_changes"
+    }
+
+    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let (change_list, latest_seq) = RegistryChange::get_since(self.since_seq, limit)?;
+
+        Ok(GetRegistryChangesResponse {
+            change_list,
+            latest_seq,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetSignatureDomain {
+    platform: Platform,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetSignatureDomainResponse {
+    pub signature_domain: SignatureDomain,
+}
+
+impl RpcParameter<AppState> for GetSignatureDomain {
+    type Response = GetSignatureDomainResponse;
+
+    fn method() -> &'static str {
+        "get_signature_domain"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        let signature_domain = context.signature_domain(self.platform).await?;
+
+        Ok(GetSignatureDomainResponse { signature_domain })
+    }
+}
@@ -3,6 +3,12 @@ use crate::rpc::prelude::*;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetTxOrdererRpcUrl {
     tx_orderer_address: Address,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    liveness_service_provider: Option<LivenessServiceProvider>,
+    #[serde(default)]
+    cluster_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -17,9 +23,22 @@ impl RpcParameter<AppState> for GetTxOrdererRpcUrl {
         "get_tx_orderer_rpc_info"
     }
 
-    // self.tx_orderer_address.as_hex_string()
     async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
-        let tx_orderer_rpc_info = TxOrdererRpcInfo::get(&self.tx_orderer_address)?;
+        let tx_orderer_rpc_info = match (
+            self.platform,
+            self.liveness_service_provider,
+            self.cluster_id,
+        ) {
+            (Some(platform), Some(liveness_service_provider), Some(cluster_id)) => {
+                TxOrdererRpcInfo::get(
+                    platform,
+                    liveness_service_provider,
+                    &cluster_id,
+                    &self.tx_orderer_address,
+                )?
+            }
+            _ => TxOrdererRpcInfo::get_by_address(&self.tx_orderer_address)?,
+        };
 
         Ok(GetTxOrdererRpcInfoResponse {
             tx_orderer_rpc_info,
@@ -3,6 +3,12 @@ use crate::rpc::prelude::*;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetTxOrdererRpcInfoList {
     tx_orderer_address_list: Vec<Address>,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    liveness_service_provider: Option<LivenessServiceProvider>,
+    #[serde(default)]
+    cluster_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -18,11 +24,32 @@ impl RpcParameter<AppState> for GetTxOrdererRpcInfoList {
     }
 
     async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
-        let tx_orderer_rpc_info_list: Vec<TxOrdererRpcInfo> = self
-            .tx_orderer_address_list
-            .into_iter()
-            .filter_map(|tx_orderer_address| TxOrdererRpcInfo::get(&tx_orderer_address).ok())
-            .collect();
+        let tx_orderer_rpc_info_list: Vec<TxOrdererRpcInfo> = match (
+            self.platform,
+            self.liveness_service_provider,
+            self.cluster_id,
+        ) {
+            (Some(platform), Some(liveness_service_provider), Some(cluster_id)) => self
+                .tx_orderer_address_list
+                .into_iter()
+                .filter_map(|tx_orderer_address| {
+                    TxOrdererRpcInfo::get(
+                        platform,
+                        liveness_service_provider,
+                        &cluster_id,
+                        &tx_orderer_address,
+                    )
+                    .ok()
+                })
+                .collect(),
+            _ => self
+                .tx_orderer_address_list
+                .into_iter()
+                .filter_map(|tx_orderer_address| {
+                    TxOrdererRpcInfo::get_by_address(&tx_orderer_address).ok()
+                })
+                .collect(),
+        };
 
         Ok(GetTxOrdererRpcInfoListResponse {
             tx_orderer_rpc_info_list,
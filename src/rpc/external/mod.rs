@@ -1,11 +1,25 @@
+mod deregister_executor;
 mod deregister_tx_orderer;
+mod get_cluster_tx_orderer_rpc_info_list;
 mod get_executor_rpc_info_list;
+mod get_registry_changes;
+mod get_signature_domain;
 mod get_tx_orderer_rpc_info;
 mod get_tx_orderer_rpc_info_list;
+mod register_executor;
 mod register_tx_orderer;
+mod renew_tx_orderer;
+mod update_tx_orderer_rpc_url;
 
+pub use deregister_executor::*;
 pub use deregister_tx_orderer::*;
+pub use get_cluster_tx_orderer_rpc_info_list::*;
 pub use get_executor_rpc_info_list::*;
+pub use get_registry_changes::*;
+pub use get_signature_domain::*;
 pub use get_tx_orderer_rpc_info::*;
 pub use get_tx_orderer_rpc_info_list::*;
+pub use register_executor::*;
 pub use register_tx_orderer::*;
+pub use renew_tx_orderer::*;
+pub use update_tx_orderer_rpc_url::*;
@@ -0,0 +1,159 @@
+use crate::{rpc::prelude::*, util::health_check};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RegisterExecutor {
+    message: RegisterExecutorMessage,
+    signature: Signature,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RegisterExecutorMessage {
+    platform: Platform,
+    rollup_id: String,
+    executor_address: Address,
+    rpc_url: String,
+    nonce: u64,
+}
+
+impl RpcParameter<AppState> for RegisterExecutor {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "register_executor"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        // Verify the message.
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.executor_address,
+            )
+            .map_err(|_| Error::InvalidSignature)?;
+        Nonce::check(&self.message.executor_address, self.message.nonce)?;
+
+        tracing::info!(
+            "Register executor - rollup_id: {:?}, address: {:?}",
+            self.message.rollup_id,
+            self.message.executor_address.as_hex_string()
+        );
+
+        // check if the executor is allowed for the rollup
+        let executor_address_list =
+            ExecutorAddressList::get_or(&self.message.rollup_id, ExecutorAddressList::default)?;
+        if !executor_address_list.contains(&self.message.executor_address) {
+            return Err(Error::NotRegisteredInExecutorList.into());
+        }
+
+        // health check
+        health_check(&self.message.rpc_url).await?;
+
+        Nonce::commit(&self.message.executor_address, self.message.nonce)?;
+
+        ExecutorRpcInfo::new(
+            self.message.rollup_id,
+            self.message.executor_address,
+            self.message.rpc_url,
+        )
+        .register()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    fn message(
+        rollup_id: &str,
+        executor_address: &Address,
+        rpc_url: &str,
+    ) -> RegisterExecutorMessage {
+        RegisterExecutorMessage {
+            platform: PLATFORM,
+            rollup_id: rollup_id.to_owned(),
+            executor_address: executor_address.clone(),
+            rpc_url: rpc_url.to_owned(),
+            nonce: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn register_allowed_executor() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let rollup_id = "register_allowed_executor";
+        let signer = signer(0x31);
+        ExecutorAddressList::put(
+            &ExecutorAddressList::new(vec![signer.address().clone()]),
+            rollup_id,
+        )
+        .unwrap();
+
+        let message = message(rollup_id, signer.address(), &rpc_url);
+        let signature = sign(&context, &signer, RegisterExecutor::method(), &message).await;
+        let register_executor = RegisterExecutor { message, signature };
+
+        assert!(register_executor.handler(context).await.is_ok());
+
+        let executor_rpc_info = ExecutorRpcInfo::get(rollup_id, signer.address()).unwrap();
+        assert_eq!(executor_rpc_info.rpc_url(), &rpc_url);
+    }
+
+    #[tokio::test]
+    async fn reject_executor_not_in_list() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let rollup_id = "reject_executor_not_in_list";
+        let other_executor_address = signer(0x33).address().clone();
+        let signer = signer(0x32);
+        ExecutorAddressList::put(
+            &ExecutorAddressList::new(vec![other_executor_address]),
+            rollup_id,
+        )
+        .unwrap();
+
+        let message = message(rollup_id, signer.address(), &rpc_url);
+        let signature = sign(&context, &signer, RegisterExecutor::method(), &message).await;
+        let register_executor = RegisterExecutor { message, signature };
+
+        assert!(register_executor.handler(context).await.is_err());
+        assert!(ExecutorRpcInfo::get(rollup_id, signer.address()).is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_wrong_signer() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let rollup_id = "register_executor_reject_wrong_signer";
+        let executor_signer = signer(0x34);
+        ExecutorAddressList::put(
+            &ExecutorAddressList::new(vec![executor_signer.address().clone()]),
+            rollup_id,
+        )
+        .unwrap();
+
+        let message = message(rollup_id, executor_signer.address(), &rpc_url);
+        let signature = sign(
+            &context,
+            &signer(0x35),
+            RegisterExecutor::method(),
+            &message,
+        )
+        .await;
+        let register_executor = RegisterExecutor { message, signature };
+
+        assert!(register_executor.handler(context).await.is_err());
+        assert!(ExecutorRpcInfo::get(rollup_id, executor_signer.address()).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{rpc::prelude::*, util::health_check};
+use crate::{
+    rpc::prelude::*,
+    util::{current_timestamp, health_check},
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RegisterTxOrderer {
@@ -14,6 +17,21 @@ struct RegisterTxOrdererMessage {
     tx_orderer_address: Address,
     external_rpc_url: String,
     cluster_rpc_url: String,
+    nonce: u64,
+}
+
+impl RegisterTxOrderer {
+    /// Check that the message is signed by the tx_orderer for this method.
+    async fn verify_signature(&self, context: &AppState) -> Result<(), Error> {
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.tx_orderer_address,
+            )
+            .map_err(|_| Error::InvalidSignature)
+    }
 }
 
 impl RpcParameter<AppState> for RegisterTxOrderer {
@@ -24,62 +42,225 @@ impl RpcParameter<AppState> for RegisterTxOrderer {
     }
 
     async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
-        // Verify the message.
-        // parameter.signature.verify_message(
-        //     parameter.message.platform.into(),
-        //     &parameter.message,
-        //     &parameter.message.tx_orderer_address,
-        // )?;
+        self.verify_signature(&context).await?;
+        Nonce::check(&self.message.tx_orderer_address, self.message.nonce)?;
 
         tracing::info!(
             "Register tx_orderer - address: {:?}",
             self.message.tx_orderer_address.as_hex_string()
         );
 
-        match self.message.platform {
-            Platform::Ethereum => {
-                let liveness_client: liveness::radius::LivenessClient = context
-                    .get_liveness_client(
-                        self.message.platform,
-                        self.message.liveness_service_provider,
-                    )
-                    .await?;
-
-                let block_number = liveness_client
-                    .publisher()
-                    .get_block_number()
-                    .await
-                    .map_err(|error| Error::LivenessClient(error.into()))?;
-
-                let tx_orderer_list = liveness_client
-                    .publisher()
-                    .get_tx_orderer_list(&self.message.cluster_id, block_number)
-                    .await
-                    .map_err(|error| Error::LivenessClient(error.into()))?;
-
-                // check if the tx_orderer is registered in the contract
-                tx_orderer_list
-                    .iter()
-                    .find(|&&address| self.message.tx_orderer_address == address)
-                    .ok_or(Error::NotRegisteredInContract)?;
-            }
-            Platform::Local => return Err(Error::UnsupportedPlatform.into()),
+        let liveness_client = context
+            .get_liveness_client(
+                self.message.platform,
+                self.message.liveness_service_provider,
+            )
+            .await?;
+
+        let block_number = liveness_client.finalized_block().await?;
+        let tx_orderer_list = liveness_client
+            .tx_orderer_list(&self.message.cluster_id, block_number)
+            .await?;
+
+        // check if the tx_orderer is registered in the contract
+        if !tx_orderer_list.contains(&self.message.tx_orderer_address) {
+            return Err(Error::NotRegisteredInContract.into());
         }
 
+        liveness_client
+            .validate_tx_orderer(&self.message.tx_orderer_address, block_number)
+            .await?;
+
         // health check
         health_check(&self.message.external_rpc_url).await?;
 
-        let tx_orderer_node_info = TxOrdererRpcInfo::new(
+        Nonce::commit(&self.message.tx_orderer_address, self.message.nonce)?;
+
+        let mut tx_orderer_rpc_info = TxOrdererRpcInfo::new(
+            self.message.platform,
+            self.message.liveness_service_provider,
+            self.message.cluster_id,
             self.message.tx_orderer_address,
             self.message.external_rpc_url,
             self.message.cluster_rpc_url,
+            block_number,
         );
+        if let Some(lease_duration) = context.config().lease_duration {
+            tx_orderer_rpc_info.set_lease_expires_at(current_timestamp() + lease_duration);
+        }
+        tx_orderer_rpc_info.register()?;
 
-        TxOrdererRpcInfo::put(
-            &tx_orderer_node_info,
-            tx_orderer_node_info.tx_orderer_address(),
-        )?;
+        TxOrdererHistoryEntry::from_rpc_info(TxOrdererAction::Register, &tx_orderer_rpc_info)
+            .with_block_number(block_number)
+            .append(tx_orderer_rpc_info.tx_orderer_address())?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    fn message(
+        cluster_id: &str,
+        tx_orderer_address: &Address,
+        rpc_url: &str,
+        nonce: u64,
+    ) -> RegisterTxOrdererMessage {
+        RegisterTxOrdererMessage {
+            platform: PLATFORM,
+            liveness_service_provider: LIVENESS_SERVICE_PROVIDER,
+            cluster_id: cluster_id.to_owned(),
+            tx_orderer_address: tx_orderer_address.clone(),
+            external_rpc_url: rpc_url.to_owned(),
+            cluster_rpc_url: rpc_url.to_owned(),
+            nonce,
+        }
+    }
+
+    #[tokio::test]
+    async fn register_cluster_member() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let cluster_id = "register_cluster_member";
+        let signer = signer(0x11);
+        liveness_client.add_tx_orderer(cluster_id, signer.address());
+        liveness_client.set_finalized_block(7);
+
+        let message = message(cluster_id, signer.address(), &rpc_url, 1);
+        let signature = sign(&context, &signer, RegisterTxOrderer::method(), &message).await;
+        let register_tx_orderer = RegisterTxOrderer { message, signature };
+
+        assert!(register_tx_orderer
+            .clone()
+            .handler(context.clone())
+            .await
+            .is_ok());
+
+        let tx_orderer_rpc_info = TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .unwrap();
+        assert_eq!(tx_orderer_rpc_info.external_rpc_url(), &rpc_url);
+        assert_eq!(tx_orderer_rpc_info.admission_block_number(), 7);
+
+        // The same signed message cannot be replayed.
+        assert!(register_tx_orderer.handler(context).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_non_member() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let cluster_id = "reject_non_member";
+        let signer = signer(0x12);
+
+        let message = message(cluster_id, signer.address(), &rpc_url, 1);
+        let signature = sign(&context, &signer, RegisterTxOrderer::method(), &message).await;
+        let register_tx_orderer = RegisterTxOrderer { message, signature };
+
+        assert!(register_tx_orderer.verify_signature(&context).await.is_ok());
+        assert!(register_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_wrong_signer() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let cluster_id = "register_reject_wrong_signer";
+        let tx_orderer_signer = signer(0x13);
+        let other_signer = signer(0x14);
+        liveness_client.add_tx_orderer(cluster_id, tx_orderer_signer.address());
+
+        let message = message(cluster_id, tx_orderer_signer.address(), &rpc_url, 1);
+        let signature = sign(
+            &context,
+            &other_signer,
+            RegisterTxOrderer::method(),
+            &message,
+        )
+        .await;
+        let register_tx_orderer = RegisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            register_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(register_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            tx_orderer_signer.address(),
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_tampered_message() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let cluster_id = "register_reject_tampered_message";
+        let signer = signer(0x15);
+        liveness_client.add_tx_orderer(cluster_id, signer.address());
+
+        let mut message = message(cluster_id, signer.address(), &rpc_url, 1);
+        let signature = sign(&context, &signer, RegisterTxOrderer::method(), &message).await;
+        message.external_rpc_url = health_server().await;
+        let register_tx_orderer = RegisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            register_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(register_tx_orderer.handler(context).await.is_err());
+        assert!(TxOrdererRpcInfo::get(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            cluster_id,
+            signer.address(),
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_signature_of_other_method() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+        let rpc_url = health_server().await;
+
+        let cluster_id = "register_reject_signature_of_other_method";
+        let signer = signer(0x16);
+        liveness_client.add_tx_orderer(cluster_id, signer.address());
+
+        let message = message(cluster_id, signer.address(), &rpc_url, 1);
+        let signature = sign(&context, &signer, "deregister_tx_orderer", &message).await;
+        let register_tx_orderer = RegisterTxOrderer { message, signature };
+
+        assert!(matches!(
+            register_tx_orderer.verify_signature(&context).await,
+            Err(Error::InvalidSignature)
+        ));
+        assert!(register_tx_orderer.handler(context).await.is_err());
+    }
+}
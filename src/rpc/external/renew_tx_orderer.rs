@@ -0,0 +1,72 @@
+use crate::{rpc::prelude::*, util::current_timestamp};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RenewTxOrderer {
+    message: RenewTxOrdererMessage,
+    signature: Signature,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RenewTxOrdererMessage {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    cluster_id: String,
+    tx_orderer_address: Address,
+    /// Seconds to extend the lease by, capped at the configured maximum
+    /// renewal window. The configured lease duration is used when unset.
+    #[serde(default)]
+    lease_duration: Option<u64>,
+    nonce: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RenewTxOrdererResponse {
+    lease_expires_at: u64,
+}
+
+impl RpcParameter<AppState> for RenewTxOrderer {
+    type Response = RenewTxOrdererResponse;
+
+    fn method() -> &'static str {
+        "renew_tx_orderer"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        // Verify the message.
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.tx_orderer_address,
+            )
+            .map_err(|_| Error::InvalidSignature)?;
+        Nonce::check(&self.message.tx_orderer_address, self.message.nonce)?;
+
+        let default_lease_duration = context
+            .config()
+            .lease_duration
+            .ok_or(Error::LeaseDisabled)?;
+        let lease_duration = self
+            .message
+            .lease_duration
+            .unwrap_or(default_lease_duration)
+            .min(context.config().max_lease_renewal_window);
+        let lease_expires_at = current_timestamp() + lease_duration;
+
+        let mut tx_orderer_rpc_info = TxOrdererRpcInfo::get_mut(
+            self.message.platform,
+            self.message.liveness_service_provider,
+            &self.message.cluster_id,
+            &self.message.tx_orderer_address,
+        )?;
+
+        Nonce::commit(&self.message.tx_orderer_address, self.message.nonce)?;
+
+        tx_orderer_rpc_info.set_lease_expires_at(lease_expires_at);
+        tx_orderer_rpc_info.update()?;
+        RegistryChange::upsert(&tx_orderer_rpc_info)?;
+
+        Ok(RenewTxOrdererResponse { lease_expires_at })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    rpc::prelude::*,
+    util::{current_timestamp, health_check},
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateTxOrdererRpcUrl {
+    message: UpdateTxOrdererRpcUrlMessage,
+    signature: Signature,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct UpdateTxOrdererRpcUrlMessage {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    cluster_id: String,
+    tx_orderer_address: Address,
+    external_rpc_url: String,
+    cluster_rpc_url: String,
+    nonce: u64,
+}
+
+impl RpcParameter<AppState> for UpdateTxOrdererRpcUrl {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "update_tx_orderer_rpc_url"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        // Verify the message.
+        let signature_domain = context.signature_domain(self.message.platform).await?;
+        self.signature
+            .verify_message(
+                self.message.platform.into(),
+                &signature_domain.wrap(Self::method(), &self.message),
+                &self.message.tx_orderer_address,
+            )
+            .map_err(|_| Error::InvalidSignature)?;
+        Nonce::check(&self.message.tx_orderer_address, self.message.nonce)?;
+
+        tracing::info!(
+            "Update tx_orderer rpc url - address: {:?}",
+            self.message.tx_orderer_address.as_hex_string()
+        );
+
+        // The registration must exist before anything is probed.
+        TxOrdererRpcInfo::get(
+            self.message.platform,
+            self.message.liveness_service_provider,
+            &self.message.cluster_id,
+            &self.message.tx_orderer_address,
+        )?;
+
+        // health check
+        health_check(&self.message.external_rpc_url).await?;
+
+        let mut tx_orderer_rpc_info = TxOrdererRpcInfo::get_mut(
+            self.message.platform,
+            self.message.liveness_service_provider,
+            &self.message.cluster_id,
+            &self.message.tx_orderer_address,
+        )?;
+
+        Nonce::commit(&self.message.tx_orderer_address, self.message.nonce)?;
+
+        tx_orderer_rpc_info
+            .set_rpc_url(self.message.external_rpc_url, self.message.cluster_rpc_url);
+        // The new URL just answered, so restart the health tracking from it.
+        tx_orderer_rpc_info.record_health_check(true, current_timestamp());
+        tx_orderer_rpc_info.update()?;
+        RegistryChange::upsert(&tx_orderer_rpc_info)?;
+
+        TxOrdererHistoryEntry::from_rpc_info(TxOrdererAction::UpdateRpcUrl, &tx_orderer_rpc_info)
+            .append(&self.message.tx_orderer_address)?;
+
+        Ok(())
+    }
+}
@@ -22,6 +22,8 @@ impl RpcParameter<AppState> for AddLivenessInfo {
             self.payload
         );
 
+        self.payload.check_platform(self.platform)?;
+
         // Save `LivenessClient` metadata.
         let mut liveness_info_list = LivenessInfoList::get_mut_or(LivenessInfoList::default)?;
         liveness_info_list.insert(self.platform, self.liveness_service_provider);
@@ -29,20 +31,51 @@ impl RpcParameter<AppState> for AddLivenessInfo {
 
         LivenessInfoPayload::put(&self.payload, self.platform, self.liveness_service_provider)?;
 
-        match &self.payload {
-            LivenessInfoPayload::Ethereum(liveness_info) => {
-                liveness::radius::LivenessClient::initialize(
-                    context.clone(),
-                    self.platform,
-                    self.liveness_service_provider,
-                    liveness_info.clone(),
-                );
-            }
-            LivenessInfoPayload::Local(_liveness_info) => {
-                todo!("Implement 'LivenessClient' for local ordering.");
-            }
-        }
+        liveness::initialize(
+            context,
+            self.platform,
+            self.liveness_service_provider,
+            self.payload,
+        );
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    #[test]
+    fn reject_radius_payload_with_unknown_field() {
+        let payload = json!({
+            "liveness_rpc_url": "http://127.0.0.1:8545",
+            "liveness_websocket_url": "ws://127.0.0.1:8546",
+            "contract_addres": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+        });
+
+        assert!(serde_json::from_value::<LivenessInfoPayload>(payload).is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_local_payload_for_chain() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let platform = Platform::Evm(31_010);
+        let add_liveness_info = AddLivenessInfo {
+            platform,
+            liveness_service_provider: LivenessServiceProvider::Radius,
+            payload: LivenessInfoPayload::Local(LivenessLocal::default()),
+        };
+
+        assert!(add_liveness_info.handler(context).await.is_err());
+        assert!(LivenessInfoPayload::get(platform, LivenessServiceProvider::Radius).is_err());
+        assert!(!LivenessInfoList::get_or(LivenessInfoList::default)
+            .unwrap()
+            .contains(platform, LivenessServiceProvider::Radius));
+    }
+}
@@ -6,6 +6,12 @@ pub struct GetLivenessInfos {}
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetLivenessInfosResponse {
     liveness_infos: Vec<((Platform, LivenessServiceProvider), LivenessInfoPayload)>,
+    liveness_client_statuses: Vec<(
+        (Platform, LivenessServiceProvider),
+        Option<LivenessClientStatus>,
+    )>,
+    liveness_endpoints: Vec<((Platform, LivenessServiceProvider), Vec<EndpointHealth>)>,
+    liveness_cache_stats: Vec<((Platform, LivenessServiceProvider), QueryCacheStats)>,
 }
 
 impl RpcParameter<AppState> for GetLivenessInfos {
@@ -15,7 +21,7 @@ impl RpcParameter<AppState> for GetLivenessInfos {
         "get_liveness_infos"
     }
 
-    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
         let liveness_info_list = LivenessInfoList::get()?;
 
         let liveness_infos: Vec<((Platform, LivenessServiceProvider), LivenessInfoPayload)> =
@@ -30,6 +36,36 @@ impl RpcParameter<AppState> for GetLivenessInfos {
                 })
                 .collect();
 
-        Ok(GetLivenessInfosResponse { liveness_infos })
+        let mut liveness_client_statuses = Vec::new();
+        let mut liveness_endpoints = Vec::new();
+        let mut liveness_cache_stats = Vec::new();
+        for (platform, service_provider) in liveness_info_list.iter() {
+            let status = context
+                .get_liveness_client_status(*platform, *service_provider)
+                .await;
+
+            liveness_client_statuses.push(((*platform, *service_provider), status));
+
+            if let Ok(liveness_client) = context
+                .get_liveness_client(*platform, *service_provider)
+                .await
+            {
+                liveness_endpoints.push((
+                    (*platform, *service_provider),
+                    liveness_client.endpoint_health_list(),
+                ));
+
+                if let Some(cache_stats) = liveness_client.cache_stats() {
+                    liveness_cache_stats.push(((*platform, *service_provider), cache_stats));
+                }
+            }
+        }
+
+        Ok(GetLivenessInfosResponse {
+            liveness_infos,
+            liveness_client_statuses,
+            liveness_endpoints,
+            liveness_cache_stats,
+        })
     }
 }
@@ -0,0 +1,25 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetReconciliationReport {}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetReconciliationReportResponse {
+    reconciliation_report: ReconciliationReport,
+}
+
+impl RpcParameter<AppState> for GetReconciliationReport {
+    type Response = GetReconciliationReportResponse;
+
+    fn method() -> &'static str {
+        "get_reconciliation_report"
+    }
+
+    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+        let reconciliation_report = ReconciliationReport::get_or(ReconciliationReport::default)?;
+
+        Ok(GetReconciliationReportResponse {
+            reconciliation_report,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::rpc::prelude::*;
+
+const DEFAULT_LIMIT: u64 = 100;
+const MAX_LIMIT: u64 = 1000;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetTxOrdererHistory {
+    pub tx_orderer_address: Address,
+    /// Index of the first entry to return, oldest first.
+    #[serde(default)]
+    pub offset: u64,
+    pub limit: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetTxOrdererHistoryResponse {
+    entry_list: Vec<TxOrdererHistoryEntry>,
+    total_count: u64,
+}
+
+impl RpcParameter<AppState> for GetTxOrdererHistory {
+    type Response = GetTxOrdererHistoryResponse;
+
+    fn method() -> &'static str {
+        "get_tx_orderer_history"
+    }
+
+    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+        let total_count =
+            TxOrdererHistory::get_or(&self.tx_orderer_address, TxOrdererHistory::default)?.len();
+        let entry_list = TxOrdererHistory::get_page(&self.tx_orderer_address, self.offset, limit)?;
+
+        Ok(GetTxOrdererHistoryResponse {
+            entry_list,
+            total_count,
+        })
+    }
+}
@@ -1,5 +1,9 @@
 pub mod get_liveness_info;
 pub mod get_liveness_infos;
+pub mod get_reconciliation_report;
+pub mod get_tx_orderer_history;
 
 pub use get_liveness_info::*;
 pub use get_liveness_infos::*;
+pub use get_reconciliation_report::*;
+pub use get_tx_orderer_history::*;
@@ -0,0 +1,61 @@
+use crate::{rpc::prelude::*, types::legacy};
+
+/// Migrate the legacy records of a cluster that has no registration in the
+/// current layout yet, which reconciliation does not walk.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MigrateLegacyTxOrderers {
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+    pub cluster_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MigrateLegacyTxOrderersResponse {
+    migrated: Vec<String>,
+}
+
+impl RpcParameter<AppState> for MigrateLegacyTxOrderers {
+    type Response = MigrateLegacyTxOrderersResponse;
+
+    fn method() -> &'static str {
+        "migrate_legacy_tx_orderers"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        tracing::info!(
+            "Migrate legacy tx_orderers - platform: {:?}, service provider: {:?}, cluster_id: {:?}",
+            self.platform,
+            self.liveness_service_provider,
+            self.cluster_id
+        );
+
+        let liveness_client = context
+            .get_liveness_client(self.platform, self.liveness_service_provider)
+            .await?;
+
+        let block_number = liveness_client.finalized_block().await?;
+        let tx_orderer_address_list = liveness_client
+            .tx_orderer_list(&self.cluster_id, block_number)
+            .await?
+            .into_inner()
+            .into_iter()
+            .map(|address| Address::from_str(self.platform.into(), &format!("0x{}", address)))
+            .collect::<Result<Vec<Address>, _>>()
+            .map_err(Error::from)?;
+
+        let migrated = legacy::TxOrdererRpcInfo::migrate_cluster(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            block_number,
+            tx_orderer_address_list,
+        )?;
+
+        Ok(MigrateLegacyTxOrderersResponse {
+            migrated: migrated
+                .iter()
+                .map(|address| address.as_hex_string())
+                .collect(),
+        })
+    }
+}
@@ -1,5 +1,18 @@
 pub mod add_liveness_info;
 pub mod debug;
+pub mod migrate_legacy_tx_orderers;
+pub mod remove_liveness_info;
+pub mod remove_tx_orderer;
+pub mod set_executor_address_list;
+pub mod update_liveness_info;
 
 pub use add_liveness_info::*;
-pub use debug::{get_liveness_info::*, get_liveness_infos::*};
+pub use debug::{
+    get_liveness_info::*, get_liveness_infos::*, get_reconciliation_report::*,
+    get_tx_orderer_history::*,
+};
+pub use migrate_legacy_tx_orderers::*;
+pub use remove_liveness_info::*;
+pub use remove_tx_orderer::*;
+pub use set_executor_address_list::*;
+pub use update_liveness_info::*;
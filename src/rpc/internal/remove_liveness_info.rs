@@ -0,0 +1,150 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RemoveLivenessInfo {
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+}
+
+impl RpcParameter<AppState> for RemoveLivenessInfo {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "remove_liveness_info"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        tracing::info!(
+            "Remove liveness info - platform: {:?}, service provider: {:?}",
+            self.platform,
+            self.liveness_service_provider
+        );
+
+        {
+            let mut liveness_info_list = LivenessInfoList::get_mut_or(LivenessInfoList::default)?;
+            if !liveness_info_list.contains(self.platform, self.liveness_service_provider) {
+                return Err(Error::LivenessInfoNotFound(
+                    self.platform,
+                    self.liveness_service_provider,
+                )
+                .into());
+            }
+            liveness_info_list.remove(self.platform, self.liveness_service_provider);
+            liveness_info_list.update()?;
+        }
+
+        LivenessInfoPayload::delete(self.platform, self.liveness_service_provider)?;
+        LivenessCheckpoint::delete(self.platform, self.liveness_service_provider)?;
+
+        context
+            .remove_liveness_client(self.platform, self.liveness_service_provider)
+            .await;
+
+        // Without a liveness client, nothing checks the membership of the
+        // registered tx_orderers anymore, so they are removed as well.
+        let cluster_id_list: Vec<String> = ClusterIdList::get_or(ClusterIdList::default)?
+            .iter()
+            .filter(|(platform, liveness_service_provider, _)| {
+                *platform == self.platform
+                    && *liveness_service_provider == self.liveness_service_provider
+            })
+            .map(|(_, _, cluster_id)| cluster_id.clone())
+            .collect();
+
+        for cluster_id in cluster_id_list {
+            let tx_orderer_list = ClusterTxOrdererList::get_or(
+                self.platform,
+                self.liveness_service_provider,
+                &cluster_id,
+                ClusterTxOrdererList::default,
+            )?;
+
+            for tx_orderer_address in tx_orderer_list.iter() {
+                let Ok(tx_orderer_rpc_info) = TxOrdererRpcInfo::get(
+                    self.platform,
+                    self.liveness_service_provider,
+                    &cluster_id,
+                    tx_orderer_address,
+                ) else {
+                    continue;
+                };
+
+                tracing::info!(
+                    "Remove tx_orderer of removed liveness info - cluster_id: {:?}, address: {:?}",
+                    cluster_id,
+                    tx_orderer_address.as_hex_string()
+                );
+
+                TxOrdererRpcInfo::deregister(
+                    self.platform,
+                    self.liveness_service_provider,
+                    &cluster_id,
+                    tx_orderer_address,
+                )?;
+
+                TxOrdererHistoryEntry::from_rpc_info(
+                    TxOrdererAction::AdminRemove,
+                    &tx_orderer_rpc_info,
+                )
+                .with_reason("liveness info removed")
+                .append(tx_orderer_address)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    #[tokio::test]
+    async fn remove_registrations_of_liveness_info() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let platform = Platform::Evm(31_011);
+        let cluster_id = "remove_registrations_of_liveness_info";
+        let tx_orderer_address = signer(0x45).address().clone();
+
+        let mut liveness_info_list =
+            LivenessInfoList::get_mut_or(LivenessInfoList::default).unwrap();
+        liveness_info_list.insert(platform, LivenessServiceProvider::Radius);
+        liveness_info_list.update().unwrap();
+
+        TxOrdererRpcInfo::new(
+            platform,
+            LivenessServiceProvider::Radius,
+            cluster_id.to_owned(),
+            tx_orderer_address.clone(),
+            "http://127.0.0.1:7000".to_owned(),
+            "http://127.0.0.1:7001".to_owned(),
+            0,
+        )
+        .register()
+        .unwrap();
+
+        let remove_liveness_info = RemoveLivenessInfo {
+            platform,
+            liveness_service_provider: LivenessServiceProvider::Radius,
+        };
+
+        assert!(remove_liveness_info.handler(context).await.is_ok());
+        assert!(TxOrdererRpcInfo::get(
+            platform,
+            LivenessServiceProvider::Radius,
+            cluster_id,
+            &tx_orderer_address,
+        )
+        .is_err());
+        assert!(!ClusterIdList::get_or(ClusterIdList::default)
+            .unwrap()
+            .iter()
+            .any(|(cluster_platform, _, _)| *cluster_platform == platform));
+
+        let history = TxOrdererHistory::get_page(&tx_orderer_address, 0, 1).unwrap();
+        assert_eq!(history[0].action, TxOrdererAction::AdminRemove);
+    }
+}
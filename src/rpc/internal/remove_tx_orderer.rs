@@ -0,0 +1,50 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RemoveTxOrderer {
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+    pub cluster_id: String,
+    pub tx_orderer_address: Address,
+    pub reason: Option<String>,
+}
+
+impl RpcParameter<AppState> for RemoveTxOrderer {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "remove_tx_orderer"
+    }
+
+    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+        tracing::info!(
+            "Remove tx_orderer - cluster_id: {:?}, address: {:?}, reason: {:?}",
+            self.cluster_id,
+            self.tx_orderer_address.as_hex_string(),
+            self.reason
+        );
+
+        let tx_orderer_rpc_info = TxOrdererRpcInfo::get(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            &self.tx_orderer_address,
+        )?;
+
+        TxOrdererRpcInfo::deregister(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            &self.tx_orderer_address,
+        )?;
+
+        let mut history_entry = TxOrdererHistoryEntry::from_rpc_info(
+            TxOrdererAction::AdminRemove,
+            &tx_orderer_rpc_info,
+        );
+        history_entry.reason = self.reason;
+        history_entry.append(&self.tx_orderer_address)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetExecutorAddressList {
+    pub rollup_id: String,
+    pub executor_address_list: Vec<Address>,
+}
+
+impl RpcParameter<AppState> for SetExecutorAddressList {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "set_executor_address_list"
+    }
+
+    async fn handler(self, _context: AppState) -> Result<Self::Response, RpcError> {
+        tracing::info!(
+            "Set executor address list - rollup_id: {:?}, executor_address_list: {:?}",
+            self.rollup_id,
+            self.executor_address_list
+                .iter()
+                .map(|executor_address| executor_address.as_hex_string())
+                .collect::<Vec<String>>()
+        );
+
+        // Executors dropped from the list lose their registration for the
+        // rollup.
+        let previous_executor_address_list =
+            ExecutorAddressList::get_or(&self.rollup_id, ExecutorAddressList::default)?;
+        let executor_address_list = ExecutorAddressList::new(self.executor_address_list);
+        for executor_address in previous_executor_address_list.iter() {
+            if !executor_address_list.contains(executor_address)
+                && ExecutorRpcInfo::get(&self.rollup_id, executor_address).is_ok()
+            {
+                tracing::info!(
+                    "Deregister executor removed from the list - rollup_id: {:?}, address: {:?}",
+                    self.rollup_id,
+                    executor_address.as_hex_string()
+                );
+                ExecutorRpcInfo::deregister(&self.rollup_id, executor_address)?;
+            }
+        }
+
+        ExecutorAddressList::put(&executor_address_list, &self.rollup_id)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::liveness::mock, test_util::*};
+
+    #[tokio::test]
+    async fn deregister_dropped_executors() {
+        let liveness_client = mock::LivenessClient::new(PLATFORM, LIVENESS_SERVICE_PROVIDER);
+        let context = app_state(&liveness_client).await;
+
+        let rollup_id = "set_executor_address_list_deregister_dropped_executors";
+        let kept_address = signer(0x43).address().clone();
+        let dropped_address = signer(0x44).address().clone();
+        for executor_address in [&kept_address, &dropped_address] {
+            ExecutorRpcInfo::new(
+                rollup_id.to_owned(),
+                executor_address.clone(),
+                "http://127.0.0.1:8000".to_owned(),
+            )
+            .register()
+            .unwrap();
+        }
+        ExecutorAddressList::put(
+            &ExecutorAddressList::new(vec![kept_address.clone(), dropped_address.clone()]),
+            rollup_id,
+        )
+        .unwrap();
+
+        let set_executor_address_list = SetExecutorAddressList {
+            rollup_id: rollup_id.to_owned(),
+            executor_address_list: vec![kept_address.clone()],
+        };
+
+        assert!(set_executor_address_list.handler(context).await.is_ok());
+        assert!(ExecutorRpcInfo::get(rollup_id, &kept_address).is_ok());
+        assert!(ExecutorRpcInfo::get(rollup_id, &dropped_address).is_err());
+        assert!(ExecutorRpcInfo::get_list_by_address(&dropped_address)
+            .unwrap()
+            .is_empty());
+    }
+}
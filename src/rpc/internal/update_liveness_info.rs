@@ -0,0 +1,48 @@
+use crate::rpc::prelude::*;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateLivenessInfo {
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+    pub payload: LivenessInfoPayload,
+}
+
+impl RpcParameter<AppState> for UpdateLivenessInfo {
+    type Response = ();
+
+    fn method() -> &'static str {
+        "update_liveness_info"
+    }
+
+    async fn handler(self, context: AppState) -> Result<Self::Response, RpcError> {
+        tracing::info!(
+            "Update liveness info - platform: {:?}, service provider: {:?}, payload: {:?}",
+            self.platform,
+            self.liveness_service_provider,
+            self.payload
+        );
+
+        self.payload.check_platform(self.platform)?;
+
+        let liveness_info_list = LivenessInfoList::get_or(LivenessInfoList::default)?;
+        if !liveness_info_list.contains(self.platform, self.liveness_service_provider) {
+            return Err(
+                Error::LivenessInfoNotFound(self.platform, self.liveness_service_provider).into(),
+            );
+        }
+
+        LivenessInfoPayload::put(&self.payload, self.platform, self.liveness_service_provider)?;
+
+        // The running client and its listener keep serving until the new one
+        // is ready. While the new one is retried, its progress is reported
+        // through `get_liveness_infos`.
+        liveness::initialize(
+            context,
+            self.platform,
+            self.liveness_service_provider,
+            self.payload,
+        );
+
+        Ok(())
+    }
+}
@@ -7,5 +7,10 @@ mod prelude {
     };
     pub use serde::{Deserialize, Serialize};
 
-    pub use crate::{client::liveness, error::Error, state::AppState, types::*};
+    pub use crate::{
+        client::liveness::{self, LivenessClient},
+        error::Error,
+        state::AppState,
+        types::*,
+    };
 }
@@ -1,12 +1,37 @@
-use std::{any::Any, sync::Arc};
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use radius_sdk::{
     kvstore::{CachedKvStore, CachedKvStoreError},
     signature::PrivateKeySigner,
 };
 use serde::Serialize;
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::{
+    client::liveness::LivenessClient,
+    error::Error,
+    types::{Config, LivenessClientStatus, LivenessServiceProvider, Platform, SignatureDomain},
+};
 
-use crate::types::{Config, LivenessServiceProvider, Platform};
+type LivenessClients = HashMap<(Platform, LivenessServiceProvider), Arc<dyn LivenessClient>>;
+type LivenessClientStatuses = HashMap<(Platform, LivenessServiceProvider), LivenessClientStatus>;
+type LivenessTasks = HashMap<(Platform, LivenessServiceProvider), LivenessTaskList>;
+
+/// Tasks driving the liveness clients of a platform and liveness service
+/// provider. A task started for a new payload runs next to the active one
+/// until its client is ready, and then replaces it.
+#[derive(Default)]
+struct LivenessTaskList {
+    active_generation: Option<u64>,
+    task_list: Vec<(u64, JoinHandle<()>)>,
+}
 
 #[derive(Clone)]
 pub struct AppState {
@@ -15,16 +40,22 @@ pub struct AppState {
 
 struct AppStateInner {
     config: Config,
-    liveness_clients: CachedKvStore,
+    liveness_clients: RwLock<LivenessClients>,
+    liveness_client_statuses: RwLock<LivenessClientStatuses>,
+    liveness_tasks: Mutex<LivenessTasks>,
+    liveness_task_generation: AtomicU64,
     signers: CachedKvStore,
 }
 
 impl AppState {
-    pub fn new(config: Config, liveness_clients: CachedKvStore, signers: CachedKvStore) -> Self {
+    pub fn new(config: Config, signers: CachedKvStore) -> Self {
         Self {
             inner: Arc::new(AppStateInner {
                 config,
-                liveness_clients,
+                liveness_clients: RwLock::new(HashMap::new()),
+                liveness_client_statuses: RwLock::new(HashMap::new()),
+                liveness_tasks: Mutex::new(HashMap::new()),
+                liveness_task_generation: AtomicU64::new(0),
                 signers,
             }),
         }
@@ -60,36 +91,168 @@ impl AppState {
     }
 
     /// Liveness client functions
-    pub async fn add_liveness_client<T>(
+    ///
+    /// Serve the client started by the task of the given generation and stop
+    /// the tasks of older generations, including the one driving the client
+    /// it replaces. Returns `false` without doing anything when a client of a
+    /// newer generation is already served.
+    pub async fn activate_liveness_client(
         &self,
         platform: Platform,
         liveness_service_provider: LivenessServiceProvider,
-        liveness_client: T,
-    ) -> Result<(), CachedKvStoreError>
-    where
-        T: Clone + Any + Send + 'static,
-    {
-        self.put_to_store(
-            &self.inner.liveness_clients,
-            (platform, liveness_service_provider),
-            liveness_client,
-        )
-        .await
+        generation: u64,
+        liveness_client: Arc<dyn LivenessClient>,
+    ) -> bool {
+        let mut liveness_clients = self.inner.liveness_clients.write().await;
+
+        {
+            let mut liveness_tasks = self.inner.liveness_tasks.lock().unwrap();
+            let liveness_task_list = liveness_tasks
+                .entry((platform, liveness_service_provider))
+                .or_default();
+
+            if liveness_task_list
+                .active_generation
+                .is_some_and(|active_generation| active_generation > generation)
+            {
+                return false;
+            }
+
+            liveness_task_list.active_generation = Some(generation);
+            liveness_task_list
+                .task_list
+                .retain(
+                    |(task_generation, handle)| match *task_generation < generation {
+                        true => {
+                            handle.abort();
+                            false
+                        }
+                        false => true,
+                    },
+                );
+        }
+
+        liveness_clients.insert((platform, liveness_service_provider), liveness_client);
+
+        true
     }
 
-    pub async fn get_liveness_client<T>(
+    pub async fn get_liveness_client(
         &self,
         platform: Platform,
         liveness_service_provider: LivenessServiceProvider,
-    ) -> Result<T, CachedKvStoreError>
-    where
-        T: Clone + Any + Send + 'static,
-    {
-        self.get_from_store(
-            &self.inner.liveness_clients,
-            (platform, liveness_service_provider),
-        )
-        .await
+    ) -> Result<Arc<dyn LivenessClient>, Error> {
+        let liveness_client = self
+            .inner
+            .liveness_clients
+            .read()
+            .await
+            .get(&(platform, liveness_service_provider))
+            .cloned();
+
+        match liveness_client {
+            Some(liveness_client) => Ok(liveness_client),
+            None => match self
+                .get_liveness_client_status(platform, liveness_service_provider)
+                .await
+            {
+                Some(status) => Err(Error::LivenessClientNotReady(
+                    platform,
+                    liveness_service_provider,
+                    status,
+                )),
+                None => Err(Error::LivenessClientNotFound(
+                    platform,
+                    liveness_service_provider,
+                )),
+            },
+        }
+    }
+
+    pub async fn set_liveness_client_status(
+        &self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        status: LivenessClientStatus,
+    ) {
+        self.inner
+            .liveness_client_statuses
+            .write()
+            .await
+            .insert((platform, liveness_service_provider), status);
+    }
+
+    pub async fn get_liveness_client_status(
+        &self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+    ) -> Option<LivenessClientStatus> {
+        self.inner
+            .liveness_client_statuses
+            .read()
+            .await
+            .get(&(platform, liveness_service_provider))
+            .cloned()
+    }
+
+    /// Generation of a new task driving a liveness client. Later tasks get
+    /// higher generations.
+    pub fn next_liveness_generation(&self) -> u64 {
+        self.inner
+            .liveness_task_generation
+            .fetch_add(1, Ordering::Relaxed)
+            + 1
+    }
+
+    /// Keep the handle of the task driving the liveness client so that it can
+    /// be stopped later. The task already running for the same key keeps
+    /// running until the new client is activated.
+    pub fn set_liveness_task(
+        &self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        generation: u64,
+        handle: JoinHandle<()>,
+    ) {
+        self.inner
+            .liveness_tasks
+            .lock()
+            .unwrap()
+            .entry((platform, liveness_service_provider))
+            .or_default()
+            .task_list
+            .push((generation, handle));
+    }
+
+    /// Stop the task of the liveness client and drop the client.
+    pub async fn remove_liveness_client(
+        &self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+    ) {
+        let liveness_task_list = self
+            .inner
+            .liveness_tasks
+            .lock()
+            .unwrap()
+            .remove(&(platform, liveness_service_provider));
+
+        if let Some(liveness_task_list) = liveness_task_list {
+            for (_, handle) in liveness_task_list.task_list {
+                handle.abort();
+            }
+        }
+
+        self.inner
+            .liveness_clients
+            .write()
+            .await
+            .remove(&(platform, liveness_service_provider));
+        self.inner
+            .liveness_client_statuses
+            .write()
+            .await
+            .remove(&(platform, liveness_service_provider));
     }
 
     /// Signer functions
@@ -108,4 +271,13 @@ impl AppState {
     ) -> Result<PrivateKeySigner, CachedKvStoreError> {
         self.get_from_store(&self.inner.signers, platform).await
     }
+
+    pub async fn signature_domain(
+        &self,
+        platform: Platform,
+    ) -> Result<SignatureDomain, CachedKvStoreError> {
+        let signer = self.get_signer(platform).await?;
+
+        Ok(SignatureDomain::new(platform, signer.address()))
+    }
 }
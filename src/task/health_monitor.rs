@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use futures::future::join_all;
+
+use crate::{
+    error::Error,
+    state::AppState,
+    types::*,
+    util::{current_timestamp, health_check_with_timeout},
+};
+
+/// Periodically probes every registered tx_orderer, marks the ones that stop
+/// answering as unhealthy and evicts them once the grace period has passed.
+pub fn initialize(context: AppState) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(context.config().health_check_interval));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = probe_all(&context).await {
+                tracing::error!("Failed to probe tx_orderers: {:?}", error);
+            }
+        }
+    });
+}
+
+async fn probe_all(context: &AppState) -> Result<(), Error> {
+    let cluster_id_list = ClusterIdList::get_or(ClusterIdList::default)?;
+
+    let mut tx_orderer_rpc_info_list = Vec::new();
+    for (platform, liveness_service_provider, cluster_id) in cluster_id_list.iter() {
+        let tx_orderer_list = ClusterTxOrdererList::get_or(
+            *platform,
+            *liveness_service_provider,
+            cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+
+        tx_orderer_rpc_info_list.extend(tx_orderer_list.iter().filter_map(|address| {
+            TxOrdererRpcInfo::get(*platform, *liveness_service_provider, cluster_id, address).ok()
+        }));
+    }
+
+    let timeout = Duration::from_secs(context.config().health_check_timeout);
+    let eviction_grace_period = context.config().eviction_grace_period;
+
+    join_all(
+        tx_orderer_rpc_info_list
+            .iter()
+            .map(|tx_orderer_rpc_info| probe(tx_orderer_rpc_info, timeout, eviction_grace_period)),
+    )
+    .await
+    .into_iter()
+    .filter_map(Result::err)
+    .for_each(|error| tracing::error!("Failed to update tx_orderer health: {:?}", error));
+
+    Ok(())
+}
+
+async fn probe(
+    tx_orderer_rpc_info: &TxOrdererRpcInfo,
+    timeout: Duration,
+    eviction_grace_period: u64,
+) -> Result<(), Error> {
+    let is_healthy = health_check_with_timeout(tx_orderer_rpc_info.external_rpc_url(), timeout)
+        .await
+        .is_ok();
+    let now = current_timestamp();
+
+    let is_expired = {
+        // The record may have been removed while the health check ran.
+        let Ok(mut tx_orderer_rpc_info) = TxOrdererRpcInfo::get_mut(
+            tx_orderer_rpc_info.platform(),
+            tx_orderer_rpc_info.liveness_service_provider(),
+            tx_orderer_rpc_info.cluster_id(),
+            tx_orderer_rpc_info.tx_orderer_address(),
+        ) else {
+            return Ok(());
+        };
+
+        let status = tx_orderer_rpc_info.status();
+        tx_orderer_rpc_info.record_health_check(is_healthy, now);
+        tx_orderer_rpc_info.update()?;
+
+        // Only status changes are published, not every health check.
+        if tx_orderer_rpc_info.status() != status {
+            if tx_orderer_rpc_info.status() == TxOrdererStatus::Unhealthy {
+                tracing::warn!(
+                    "Tx_orderer became unhealthy - cluster_id: {:?}, address: {:?}",
+                    tx_orderer_rpc_info.cluster_id(),
+                    tx_orderer_rpc_info.tx_orderer_address().as_hex_string()
+                );
+            }
+
+            RegistryChange::upsert(&tx_orderer_rpc_info)?;
+        }
+
+        tx_orderer_rpc_info.is_expired(now, eviction_grace_period)
+    };
+
+    if is_expired {
+        tracing::info!(
+            "Evict tx_orderer - cluster_id: {:?}, address: {:?}",
+            tx_orderer_rpc_info.cluster_id(),
+            tx_orderer_rpc_info.tx_orderer_address().as_hex_string()
+        );
+
+        TxOrdererRpcInfo::deregister(
+            tx_orderer_rpc_info.platform(),
+            tx_orderer_rpc_info.liveness_service_provider(),
+            tx_orderer_rpc_info.cluster_id(),
+            tx_orderer_rpc_info.tx_orderer_address(),
+        )?;
+
+        TxOrdererHistoryEntry::from_rpc_info(TxOrdererAction::Evict, tx_orderer_rpc_info)
+            .with_reason(format!("not seen for {} seconds", eviction_grace_period))
+            .append(tx_orderer_rpc_info.tx_orderer_address())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use crate::{error::Error, state::AppState, types::*, util::current_timestamp};
+
+/// Periodically removes the registrations whose lease expired without being
+/// renewed through `renew_tx_orderer`. Does nothing when leases are disabled.
+pub fn initialize(context: AppState) {
+    if context.config().lease_duration.is_none() {
+        tracing::info!("Leases are disabled, registrations do not expire.");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(context.config().lease_sweep_interval));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = sweep_all(&context) {
+                tracing::error!("Failed to sweep expired leases: {:?}", error);
+            }
+        }
+    });
+}
+
+fn sweep_all(context: &AppState) -> Result<(), Error> {
+    let cluster_id_list = ClusterIdList::get_or(ClusterIdList::default)?;
+    let now = current_timestamp();
+
+    for (platform, liveness_service_provider, cluster_id) in cluster_id_list.iter() {
+        let tx_orderer_list = ClusterTxOrdererList::get_or(
+            *platform,
+            *liveness_service_provider,
+            cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+
+        for tx_orderer_address in tx_orderer_list.iter() {
+            let is_expired = {
+                let Ok(mut tx_orderer_rpc_info) = TxOrdererRpcInfo::get_mut(
+                    *platform,
+                    *liveness_service_provider,
+                    cluster_id,
+                    tx_orderer_address,
+                ) else {
+                    continue;
+                };
+
+                match tx_orderer_rpc_info.lease_expires_at() {
+                    Some(lease_expires_at) => lease_expires_at <= now,
+                    // Registered before leases were enabled, so give it time
+                    // to start renewing.
+                    None => {
+                        tx_orderer_rpc_info
+                            .set_lease_expires_at(now + context.config().lease_migration_window);
+                        tx_orderer_rpc_info.update()?;
+                        RegistryChange::upsert(&tx_orderer_rpc_info)?;
+
+                        false
+                    }
+                }
+            };
+
+            if is_expired {
+                tracing::info!(
+                    "Lease expired - cluster_id: {:?}, address: {:?}",
+                    cluster_id,
+                    tx_orderer_address.as_hex_string()
+                );
+
+                TxOrdererRpcInfo::deregister(
+                    *platform,
+                    *liveness_service_provider,
+                    cluster_id,
+                    tx_orderer_address,
+                )?;
+
+                TxOrdererHistoryEntry::new(
+                    TxOrdererAction::Evict,
+                    *platform,
+                    *liveness_service_provider,
+                    cluster_id,
+                )
+                .with_reason("lease expired")
+                .append(tx_orderer_address)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod health_monitor;
+pub mod lease_sweeper;
+pub mod reconciliation;
@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use radius_sdk::signature::Address;
+
+use crate::{
+    client::liveness,
+    error::Error,
+    state::AppState,
+    types::{legacy, *},
+    util::current_timestamp,
+};
+
+/// Periodically compares the registry of every known cluster with the cluster
+/// membership in its liveness contract and removes the entries of tx_orderers
+/// that are no longer part of the cluster.
+pub fn initialize(context: AppState) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            context.config().reconciliation_interval,
+        ));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = reconcile_all(&context).await {
+                tracing::error!("Failed to reconcile the registry: {:?}", error);
+            }
+        }
+    });
+}
+
+async fn reconcile_all(context: &AppState) -> Result<(), Error> {
+    let cluster_id_list = ClusterIdList::get_or(ClusterIdList::default)?;
+
+    let mut report = ReconciliationReport {
+        reconciled_at: current_timestamp(),
+        cluster_list: Vec::new(),
+    };
+
+    for (platform, liveness_service_provider, cluster_id) in cluster_id_list.iter() {
+        let mut cluster_reconciliation = ClusterReconciliation {
+            platform: *platform,
+            liveness_service_provider: *liveness_service_provider,
+            cluster_id: cluster_id.clone(),
+            block_number: None,
+            pruned: Vec::new(),
+            migrated: Vec::new(),
+            unregistered: Vec::new(),
+            error: None,
+        };
+
+        if let Err(error) = reconcile(context, &mut cluster_reconciliation).await {
+            tracing::error!(
+                "Failed to reconcile cluster {:?} ({:?}, {:?}): {:?}",
+                cluster_id,
+                platform,
+                liveness_service_provider,
+                error
+            );
+            cluster_reconciliation.error = Some(error.to_string());
+        }
+
+        report.cluster_list.push(cluster_reconciliation);
+    }
+
+    ReconciliationReport::put(&report)?;
+
+    Ok(())
+}
+
+async fn reconcile(
+    context: &AppState,
+    cluster_reconciliation: &mut ClusterReconciliation,
+) -> Result<(), Error> {
+    let platform = cluster_reconciliation.platform;
+    let liveness_service_provider = cluster_reconciliation.liveness_service_provider;
+    let cluster_id = cluster_reconciliation.cluster_id.clone();
+
+    let liveness_client = context
+        .get_liveness_client(platform, liveness_service_provider)
+        .await?;
+
+    let block_number = liveness_client.finalized_block().await?;
+    cluster_reconciliation.block_number = Some(block_number);
+
+    let registered_list = liveness_client
+        .tx_orderer_list(&cluster_id, block_number)
+        .await?;
+
+    let tx_orderer_list = ClusterTxOrdererList::get_or(
+        platform,
+        liveness_service_provider,
+        &cluster_id,
+        ClusterTxOrdererList::default,
+    )?;
+
+    let tx_orderer_address_list = ClusterMembership::new(
+        tx_orderer_list
+            .iter()
+            .map(|tx_orderer_address| tx_orderer_address.as_hex_string()),
+    );
+
+    for tx_orderer_address in tx_orderer_list.iter() {
+        if registered_list.contains(tx_orderer_address) {
+            continue;
+        }
+
+        tracing::info!(
+            "Reconciliation removes tx_orderer - cluster_id: {:?}, address: {:?}",
+            cluster_id,
+            tx_orderer_address.as_hex_string()
+        );
+
+        TxOrdererRpcInfo::deregister(
+            platform,
+            liveness_service_provider,
+            &cluster_id,
+            tx_orderer_address,
+        )?;
+        TxOrdererHistoryEntry::new(
+            TxOrdererAction::LeaveCluster,
+            platform,
+            liveness_service_provider,
+            &cluster_id,
+        )
+        .with_block_number(block_number)
+        .with_reason("removed by reconciliation")
+        .append(tx_orderer_address)?;
+        cluster_reconciliation
+            .pruned
+            .push(tx_orderer_address.as_hex_string());
+    }
+
+    let unregistered_list = registered_list
+        .into_inner()
+        .into_iter()
+        .filter(|address| !tx_orderer_address_list.contains_hex(address))
+        .map(|address| Address::from_str(platform.into(), &format!("0x{}", address)))
+        .collect::<Result<Vec<Address>, _>>()?;
+
+    // Members without a record may still have one in the address-keyed
+    // layout of older versions.
+    let migrated = legacy::TxOrdererRpcInfo::migrate_cluster(
+        platform,
+        liveness_service_provider,
+        &cluster_id,
+        block_number,
+        unregistered_list.clone(),
+    )?;
+
+    cluster_reconciliation.migrated = migrated
+        .iter()
+        .map(|address| address.as_hex_string())
+        .collect();
+    cluster_reconciliation.unregistered = unregistered_list
+        .iter()
+        .map(|address| address.as_hex_string())
+        .filter(|address| !cluster_reconciliation.migrated.contains(address))
+        .collect();
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Once},
+};
+
+use radius_sdk::{
+    kvstore::{CachedKvStore, KvStore},
+    signature::{PrivateKeySigner, Signature},
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+use crate::{
+    client::liveness::mock,
+    state::AppState,
+    types::{Config, FinalityPolicy, LivenessServiceProvider, Platform},
+};
+
+pub const PLATFORM: Platform = Platform::Ethereum;
+pub const LIVENESS_SERVICE_PROVIDER: LivenessServiceProvider = LivenessServiceProvider::Radius;
+
+/// Seed of the key the seeder signs with.
+const SEEDER_KEY_SEED: u8 = 0x01;
+
+static INIT_DATABASE: Once = Once::new();
+
+/// Open the database shared by every test of the process. Tests run in
+/// parallel on the same database, so each test has to use its own keys and
+/// cluster IDs.
+pub fn init_database() {
+    INIT_DATABASE.call_once(|| {
+        let database_path: PathBuf =
+            std::env::temp_dir().join(format!("seeder-test-{}", std::process::id()));
+
+        KvStore::open(database_path).unwrap().init();
+    });
+}
+
+/// A signer whose private key is the seed repeated 32 times. Seeds up to
+/// `0x7f` give valid keys.
+pub fn signer(seed: u8) -> PrivateKeySigner {
+    let private_key = format!("0x{}", format!("{:02x}", seed).repeat(32));
+
+    PrivateKeySigner::from_str(PLATFORM.into(), private_key).unwrap()
+}
+
+/// App state with the database open, the seeder signer set and the mock
+/// liveness client served for `PLATFORM` and `LIVENESS_SERVICE_PROVIDER`.
+pub async fn app_state(liveness_client: &mock::LivenessClient) -> AppState {
+    init_database();
+
+    let config = Config {
+        path: PathBuf::new(),
+        external_rpc_url: "http://127.0.0.1:6000".to_owned(),
+        internal_rpc_url: "http://127.0.0.1:6001".to_owned(),
+        signing_key: String::new(),
+        health_check_interval: 30,
+        health_check_timeout: 3,
+        eviction_grace_period: 600,
+        reconciliation_interval: 300,
+        finality_policy: FinalityPolicy::Latest,
+        lease_duration: None,
+        max_lease_renewal_window: 86400,
+        lease_sweep_interval: 60,
+        lease_migration_window: 604800,
+    };
+    let context = AppState::new(config, CachedKvStore::default());
+
+    context
+        .add_signer(PLATFORM, signer(SEEDER_KEY_SEED))
+        .await
+        .unwrap();
+
+    let generation = context.next_liveness_generation();
+    context
+        .activate_liveness_client(
+            PLATFORM,
+            LIVENESS_SERVICE_PROVIDER,
+            generation,
+            Arc::new(liveness_client.clone()),
+        )
+        .await;
+
+    context
+}
+
+/// Sign the message the way the RPC handler of `method` verifies it.
+pub async fn sign<T>(
+    context: &AppState,
+    signer: &PrivateKeySigner,
+    method: &str,
+    message: &T,
+) -> Signature
+where
+    T: Serialize,
+{
+    let signature_domain = context.signature_domain(PLATFORM).await.unwrap();
+
+    signer
+        .sign_message(signature_domain.wrap(method, message))
+        .unwrap()
+}
+
+/// Serve an HTTP endpoint that answers every request, so that health checks
+/// against the returned URL pass.
+pub async fn health_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let rpc_url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .await;
+        }
+    });
+
+    rpc_url
+}
+
+/// Serve a JSON-RPC node that answers each request with what `handler`
+/// returns for its method and parameters, or with an error for `None`.
+pub async fn mock_node<F>(handler: F) -> String
+where
+    F: Fn(&str, &Value) -> Option<Value> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let rpc_url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let handler = handler.clone();
+
+            tokio::spawn(async move {
+                let Some(request) = read_request_body(&mut stream).await else {
+                    return;
+                };
+                let request: Value = serde_json::from_slice(&request).unwrap_or_default();
+                let method = request["method"].as_str().unwrap_or_default();
+
+                let response = match handler(method, &request["params"]) {
+                    Some(result) => json!({"jsonrpc": "2.0", "id": request["id"], "result": result}),
+                    None => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": {"code": -32601, "message": format!("unsupported method {}", method)},
+                    }),
+                }
+                .to_string();
+
+                let _ = stream
+                    .write_all(
+                        format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                            response.len(),
+                            response
+                        )
+                        .as_bytes(),
+                    )
+                    .await;
+            });
+        }
+    });
+
+    rpc_url
+}
+
+async fn read_request_body(stream: &mut tokio::net::TcpStream) -> Option<Vec<u8>> {
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 4096];
+
+    loop {
+        let length = stream.read(&mut buffer).await.ok()?;
+        if length == 0 {
+            return None;
+        }
+        request.extend_from_slice(&buffer[..length]);
+
+        let Some(header_end) = request.windows(4).position(|window| window == b"\r\n\r\n") else {
+            continue;
+        };
+        let header = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+        let content_length: usize = header
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|content_length| content_length.trim().parse().ok())
+            .unwrap_or_default();
+
+        let body_start = header_end + 4;
+        while request.len() < body_start + content_length {
+            let length = stream.read(&mut buffer).await.ok()?;
+            if length == 0 {
+                return None;
+            }
+            request.extend_from_slice(&buffer[..length]);
+        }
+
+        return Some(request[body_start..body_start + content_length].to_vec());
+    }
+}
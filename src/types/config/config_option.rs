@@ -7,6 +7,16 @@ use super::ConfigPath;
 
 const DEFAULT_SEEDER_EXTERNAL_RPC_URL: &str = "http://127.0.0.1:6000";
 const DEFAULT_SEEDER_INTERNAL_RPC_URL: &str = "http://127.0.0.1:6001";
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: u64 = 3;
+pub const DEFAULT_EVICTION_GRACE_PERIOD: u64 = 600;
+pub const DEFAULT_RECONCILIATION_INTERVAL: u64 = 300;
+pub const DEFAULT_FINALITY_POLICY: &str = "block_margin";
+pub const DEFAULT_CONFIRMATION_DEPTH: u64 = 12;
+const DEFAULT_LEASE_DURATION: u64 = 3600;
+pub const DEFAULT_MAX_LEASE_RENEWAL_WINDOW: u64 = 86400;
+pub const DEFAULT_LEASE_SWEEP_INTERVAL: u64 = 60;
+pub const DEFAULT_LEASE_MIGRATION_WINDOW: u64 = 604800;
 
 #[derive(Debug, Deserialize, Parser, Serialize)]
 pub struct ConfigOption {
@@ -22,6 +32,46 @@ pub struct ConfigOption {
     #[clap(long = "seeder-internal-rpc-url")]
     pub seeder_internal_rpc_url: Option<String>,
 
+    #[doc = "Set the interval in seconds between tx_orderer health checks"]
+    #[clap(long = "health-check-interval")]
+    pub health_check_interval: Option<u64>,
+
+    #[doc = "Set the timeout in seconds of a single tx_orderer health check"]
+    #[clap(long = "health-check-timeout")]
+    pub health_check_timeout: Option<u64>,
+
+    #[doc = "Set the seconds an unhealthy tx_orderer is kept before eviction"]
+    #[clap(long = "eviction-grace-period")]
+    pub eviction_grace_period: Option<u64>,
+
+    #[doc = "Set the interval in seconds between registry reconciliations"]
+    #[clap(long = "reconciliation-interval")]
+    pub reconciliation_interval: Option<u64>,
+
+    #[doc = "Set the block contract checks are made at (latest, block_margin or confirmations)"]
+    #[clap(long = "finality-policy")]
+    pub finality_policy: Option<String>,
+
+    #[doc = "Set the number of confirmations for the confirmations finality policy"]
+    #[clap(long = "confirmation-depth")]
+    pub confirmation_depth: Option<u64>,
+
+    #[doc = "Set the seconds a registration lasts without being renewed (0 disables leases)"]
+    #[clap(long = "lease-duration")]
+    pub lease_duration: Option<u64>,
+
+    #[doc = "Set the maximum seconds a single renewal can extend a lease by"]
+    #[clap(long = "max-lease-renewal-window")]
+    pub max_lease_renewal_window: Option<u64>,
+
+    #[doc = "Set the interval in seconds between sweeps of expired leases"]
+    #[clap(long = "lease-sweep-interval")]
+    pub lease_sweep_interval: Option<u64>,
+
+    #[doc = "Set the seconds a registration made before leases were enabled has to start renewing"]
+    #[clap(long = "lease-migration-window")]
+    pub lease_migration_window: Option<u64>,
+
     #[doc = "Set the seeder singing key"]
     #[clap(long = "seeder-signing-key")]
     pub signing_key: Option<String>,
@@ -33,6 +83,16 @@ impl Default for ConfigOption {
             path: Some(ConfigPath::default().as_ref().into()),
             seeder_external_rpc_url: Some(DEFAULT_SEEDER_EXTERNAL_RPC_URL.into()),
             seeder_internal_rpc_url: Some(DEFAULT_SEEDER_INTERNAL_RPC_URL.into()),
+            health_check_interval: Some(DEFAULT_HEALTH_CHECK_INTERVAL),
+            health_check_timeout: Some(DEFAULT_HEALTH_CHECK_TIMEOUT),
+            eviction_grace_period: Some(DEFAULT_EVICTION_GRACE_PERIOD),
+            reconciliation_interval: Some(DEFAULT_RECONCILIATION_INTERVAL),
+            finality_policy: Some(DEFAULT_FINALITY_POLICY.into()),
+            confirmation_depth: Some(DEFAULT_CONFIRMATION_DEPTH),
+            lease_duration: Some(DEFAULT_LEASE_DURATION),
+            max_lease_renewal_window: Some(DEFAULT_MAX_LEASE_RENEWAL_WINDOW),
+            lease_sweep_interval: Some(DEFAULT_LEASE_SWEEP_INTERVAL),
+            lease_migration_window: Some(DEFAULT_LEASE_MIGRATION_WINDOW),
             signing_key: Some("".to_owned()),
         }
     }
@@ -56,6 +116,98 @@ impl ConfigOption {
             &self.seeder_internal_rpc_url,
         );
 
+        set_toml_comment(
+            &mut toml_string,
+            "Set the interval in seconds between tx_orderer health checks",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "health_check_interval",
+            &self.health_check_interval,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the timeout in seconds of a single tx_orderer health check",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "health_check_timeout",
+            &self.health_check_timeout,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the seconds an unhealthy tx_orderer is kept before eviction",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "eviction_grace_period",
+            &self.eviction_grace_period,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the interval in seconds between registry reconciliations",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "reconciliation_interval",
+            &self.reconciliation_interval,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the block contract checks are made at (latest, block_margin or confirmations)",
+        );
+        set_toml_name_value(&mut toml_string, "finality_policy", &self.finality_policy);
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the number of confirmations for the confirmations finality policy",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "confirmation_depth",
+            &self.confirmation_depth,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the seconds a registration lasts without being renewed (0 disables leases)",
+        );
+        set_toml_name_value(&mut toml_string, "lease_duration", &self.lease_duration);
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the maximum seconds a single renewal can extend a lease by",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "max_lease_renewal_window",
+            &self.max_lease_renewal_window,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the interval in seconds between sweeps of expired leases",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "lease_sweep_interval",
+            &self.lease_sweep_interval,
+        );
+
+        set_toml_comment(
+            &mut toml_string,
+            "Set the seconds a registration made before leases were enabled has to start renewing",
+        );
+        set_toml_name_value(
+            &mut toml_string,
+            "lease_migration_window",
+            &self.lease_migration_window,
+        );
+
         toml_string
     }
 
@@ -74,6 +226,46 @@ impl ConfigOption {
                 .clone_from(&other.seeder_internal_rpc_url)
         }
 
+        if other.health_check_interval.is_some() {
+            self.health_check_interval = other.health_check_interval
+        }
+
+        if other.health_check_timeout.is_some() {
+            self.health_check_timeout = other.health_check_timeout
+        }
+
+        if other.eviction_grace_period.is_some() {
+            self.eviction_grace_period = other.eviction_grace_period
+        }
+
+        if other.reconciliation_interval.is_some() {
+            self.reconciliation_interval = other.reconciliation_interval
+        }
+
+        if other.finality_policy.is_some() {
+            self.finality_policy.clone_from(&other.finality_policy)
+        }
+
+        if other.confirmation_depth.is_some() {
+            self.confirmation_depth = other.confirmation_depth
+        }
+
+        if other.lease_duration.is_some() {
+            self.lease_duration = other.lease_duration
+        }
+
+        if other.max_lease_renewal_window.is_some() {
+            self.max_lease_renewal_window = other.max_lease_renewal_window
+        }
+
+        if other.lease_sweep_interval.is_some() {
+            self.lease_sweep_interval = other.lease_sweep_interval
+        }
+
+        if other.lease_migration_window.is_some() {
+            self.lease_migration_window = other.lease_migration_window
+        }
+
         self
     }
 }
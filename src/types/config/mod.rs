@@ -23,6 +23,20 @@ pub struct Config {
     pub internal_rpc_url: String,
 
     pub signing_key: String,
+
+    pub health_check_interval: u64,
+    pub health_check_timeout: u64,
+    pub eviction_grace_period: u64,
+
+    pub reconciliation_interval: u64,
+
+    pub finality_policy: FinalityPolicy,
+
+    /// `None` when leases are disabled.
+    pub lease_duration: Option<u64>,
+    pub max_lease_renewal_window: u64,
+    pub lease_sweep_interval: u64,
+    pub lease_migration_window: u64,
 }
 
 impl Config {
@@ -61,6 +75,48 @@ impl Config {
                 .seeder_internal_rpc_url
                 .ok_or(ConfigError::EmptyInternalRpcUrl)?,
             signing_key,
+            // Config files written before these options existed do not have
+            // them, so they fall back to the defaults.
+            health_check_interval: non_zero(
+                merged_config_option
+                    .health_check_interval
+                    .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL),
+                ConfigError::ZeroHealthCheckInterval,
+            )?,
+            health_check_timeout: non_zero(
+                merged_config_option
+                    .health_check_timeout
+                    .unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT),
+                ConfigError::ZeroHealthCheckTimeout,
+            )?,
+            eviction_grace_period: merged_config_option
+                .eviction_grace_period
+                .unwrap_or(DEFAULT_EVICTION_GRACE_PERIOD),
+            reconciliation_interval: non_zero(
+                merged_config_option
+                    .reconciliation_interval
+                    .unwrap_or(DEFAULT_RECONCILIATION_INTERVAL),
+                ConfigError::ZeroReconciliationInterval,
+            )?,
+            finality_policy: FinalityPolicy::from_config_option(&merged_config_option)?,
+            // Leases stay disabled for config files written before they
+            // existed, so upgrading does not evict tx_orderers that never
+            // renew.
+            lease_duration: merged_config_option
+                .lease_duration
+                .filter(|lease_duration| *lease_duration != 0),
+            max_lease_renewal_window: merged_config_option
+                .max_lease_renewal_window
+                .unwrap_or(DEFAULT_MAX_LEASE_RENEWAL_WINDOW),
+            lease_sweep_interval: non_zero(
+                merged_config_option
+                    .lease_sweep_interval
+                    .unwrap_or(DEFAULT_LEASE_SWEEP_INTERVAL),
+                ConfigError::ZeroLeaseSweepInterval,
+            )?,
+            lease_migration_window: merged_config_option
+                .lease_migration_window
+                .unwrap_or(DEFAULT_LEASE_MIGRATION_WINDOW),
         })
     }
 
@@ -78,6 +134,61 @@ impl Config {
     }
 }
 
+/// Reject a zero interval, which `tokio::time::interval` panics on.
+fn non_zero(value: u64, error: ConfigError) -> Result<u64, ConfigError> {
+    match value {
+        0 => Err(error),
+        value => Ok(value),
+    }
+}
+
+/// Block at which cluster membership is read for every contract check, so that
+/// registration, deregistration and reconciliation agree with each other.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinalityPolicy {
+    /// The latest block.
+    Latest,
+    /// The latest block minus the block margin of the liveness contract.
+    BlockMargin,
+    /// The latest block minus a fixed number of confirmations.
+    Confirmations(u64),
+}
+
+impl FinalityPolicy {
+    fn from_config_option(config_option: &ConfigOption) -> Result<Self, ConfigError> {
+        let finality_policy = config_option
+            .finality_policy
+            .as_deref()
+            .unwrap_or(DEFAULT_FINALITY_POLICY);
+
+        match finality_policy {
+            "latest" => Ok(Self::Latest),
+            "block_margin" => Ok(Self::BlockMargin),
+            "confirmations" => Ok(Self::Confirmations(
+                config_option
+                    .confirmation_depth
+                    .unwrap_or(DEFAULT_CONFIRMATION_DEPTH),
+            )),
+            _ => Err(ConfigError::InvalidFinalityPolicy(
+                finality_policy.to_owned(),
+            )),
+        }
+    }
+
+    /// The block to check at, given the latest block and the block margin of
+    /// the liveness contract.
+    pub fn block_number(&self, latest_block_number: u64, block_margin: u64) -> u64 {
+        match self {
+            Self::Latest => latest_block_number,
+            Self::BlockMargin => latest_block_number.saturating_sub(block_margin),
+            Self::Confirmations(confirmation_depth) => {
+                latest_block_number.saturating_sub(*confirmation_depth)
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ConfigError {
     Load(std::io::Error),
@@ -85,6 +196,11 @@ pub enum ConfigError {
     EmptyExternalRpcUrl,
     EmptyInternalRpcUrl,
     EmptySigningKey,
+    ZeroHealthCheckInterval,
+    ZeroHealthCheckTimeout,
+    ZeroReconciliationInterval,
+    InvalidFinalityPolicy(String),
+    ZeroLeaseSweepInterval,
     RemoveConfigDirectory(std::io::Error),
     CreateConfigDirectory(std::io::Error),
     CreateConfigFile(std::io::Error),
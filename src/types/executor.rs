@@ -1,20 +1,30 @@
-use crate::types::prelude::*;
+use std::collections::btree_set::{BTreeSet, Iter};
 
+use crate::{error::Error, types::prelude::*};
+
+/// RPC URL an executor serves a rollup at. An executor can serve several
+/// rollups, so the record is scoped by the rollup ID.
 #[derive(Clone, Debug, Deserialize, Serialize, Model)]
-#[kvstore(key(address: &Address))]
+#[kvstore(key(rollup_id: &str, address: &Address))]
 pub struct ExecutorRpcInfo {
+    rollup_id: String,
     executor_address: Address,
     rpc_url: String,
 }
 
 impl ExecutorRpcInfo {
-    pub fn new(executor_address: Address, rpc_url: String) -> Self {
+    pub fn new(rollup_id: String, executor_address: Address, rpc_url: String) -> Self {
         Self {
+            rollup_id,
             executor_address,
             rpc_url,
         }
     }
 
+    pub fn rollup_id(&self) -> &String {
+        &self.rollup_id
+    }
+
     pub fn address(&self) -> &Address {
         &self.executor_address
     }
@@ -26,4 +36,81 @@ impl ExecutorRpcInfo {
     pub fn into_rpc_url(self) -> String {
         self.rpc_url
     }
+
+    /// Store the record and index its rollup under the executor address.
+    pub fn register(&self) -> Result<(), Error> {
+        Self::put(self, &self.rollup_id, &self.executor_address)?;
+
+        let mut rollup_id_list = ExecutorRollupIdList::get_mut_or(
+            &self.executor_address,
+            ExecutorRollupIdList::default,
+        )?;
+        rollup_id_list.insert(&self.rollup_id);
+        rollup_id_list.update()?;
+
+        Ok(())
+    }
+
+    /// Remove the record of the rollup and drop the rollup from the address
+    /// index. The records of the other rollups the executor serves are kept.
+    pub fn deregister(rollup_id: &str, address: &Address) -> Result<(), Error> {
+        Self::delete(rollup_id, address)?;
+
+        let mut rollup_id_list =
+            ExecutorRollupIdList::get_mut_or(address, ExecutorRollupIdList::default)?;
+        rollup_id_list.remove(rollup_id);
+        rollup_id_list.update()?;
+
+        Ok(())
+    }
+
+    /// Records of every rollup the executor serves.
+    pub fn get_list_by_address(address: &Address) -> Result<Vec<Self>, Error> {
+        let rollup_id_list = ExecutorRollupIdList::get_or(address, ExecutorRollupIdList::default)?;
+
+        rollup_id_list
+            .iter()
+            .map(|rollup_id| Self::get(rollup_id, address).map_err(Error::from))
+            .collect()
+    }
+}
+
+/// Rollups an executor has registered for, indexed by its address.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address))]
+pub struct ExecutorRollupIdList(BTreeSet<String>);
+
+impl ExecutorRollupIdList {
+    pub fn insert(&mut self, rollup_id: &str) {
+        self.0.insert(rollup_id.to_owned());
+    }
+
+    pub fn remove(&mut self, rollup_id: &str) {
+        self.0.remove(rollup_id);
+    }
+
+    pub fn iter(&self) -> Iter<'_, String> {
+        self.0.iter()
+    }
+}
+
+/// Executors allowed to register for a rollup.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(rollup_id: &str))]
+pub struct ExecutorAddressList(Vec<Address>);
+
+impl ExecutorAddressList {
+    pub fn new(executor_address_list: Vec<Address>) -> Self {
+        Self(executor_address_list)
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.0
+            .iter()
+            .any(|executor_address| executor_address == address)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Address> {
+        self.0.iter()
+    }
 }
@@ -0,0 +1,131 @@
+use crate::{
+    error::Error,
+    types::{prelude::*, LivenessServiceProvider, Platform, TxOrdererRpcInfo},
+    util::current_timestamp,
+};
+
+/// Number of entries in the append-only history of a tx_orderer address. The
+/// entries themselves are stored one per key so that appending does not
+/// rewrite the whole history.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address))]
+pub struct TxOrdererHistory(u64);
+
+impl TxOrdererHistory {
+    pub fn len(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Entries `[offset, offset + limit)` of the history, oldest first.
+    pub fn get_page(
+        tx_orderer_address: &Address,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<TxOrdererHistoryEntry>, Error> {
+        let history = Self::get_or(tx_orderer_address, Self::default)?;
+        let end = offset.saturating_add(limit).min(history.len());
+
+        (offset..end)
+            .map(|index| TxOrdererHistoryEntry::get(tx_orderer_address, index).map_err(Error::from))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxOrdererAction {
+    Register,
+    /// Moved from the address-keyed layout of older versions.
+    Migrate,
+    /// Registration restored after the block that removed it was reorganized.
+    Restore,
+    UpdateRpcUrl,
+    Deregister,
+    /// Removed because it left its cluster on chain.
+    LeaveCluster,
+    /// Removed by the health monitor or the lease sweeper.
+    Evict,
+    /// Removed by an operator through the internal RPC.
+    AdminRemove,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address, index: u64))]
+pub struct TxOrdererHistoryEntry {
+    pub index: u64,
+    pub action: TxOrdererAction,
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+    pub cluster_id: String,
+    pub timestamp: u64,
+    /// Liveness block the action was decided at, for actions that checked the
+    /// liveness contract.
+    pub block_number: Option<u64>,
+    pub reason: Option<String>,
+    /// RPC URLs of the record after the action, or before it for removals.
+    pub external_rpc_url: Option<String>,
+    pub cluster_rpc_url: Option<String>,
+}
+
+impl TxOrdererHistoryEntry {
+    pub fn new(
+        action: TxOrdererAction,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            index: 0,
+            action,
+            platform,
+            liveness_service_provider,
+            cluster_id: cluster_id.into(),
+            timestamp: current_timestamp(),
+            block_number: None,
+            reason: None,
+            external_rpc_url: None,
+            cluster_rpc_url: None,
+        }
+    }
+
+    pub fn from_rpc_info(action: TxOrdererAction, tx_orderer_rpc_info: &TxOrdererRpcInfo) -> Self {
+        Self {
+            external_rpc_url: Some(tx_orderer_rpc_info.external_rpc_url().clone()),
+            cluster_rpc_url: Some(tx_orderer_rpc_info.cluster_rpc_url().clone()),
+            ..Self::new(
+                action,
+                tx_orderer_rpc_info.platform(),
+                tx_orderer_rpc_info.liveness_service_provider(),
+                tx_orderer_rpc_info.cluster_id(),
+            )
+        }
+    }
+
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.block_number = Some(block_number);
+        self
+    }
+
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Append the entry to the history of the address.
+    pub fn append(mut self, tx_orderer_address: &Address) -> Result<(), Error> {
+        let mut history =
+            TxOrdererHistory::get_mut_or(tx_orderer_address, TxOrdererHistory::default)?;
+
+        self.index = history.len();
+        Self::put(&self, tx_orderer_address, self.index)?;
+
+        history.0 += 1;
+        history.update()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Storage layouts that predate the current registry. They are only kept
+//! around to migrate and clean up entries written by older versions.
+
+use crate::{
+    error::Error,
+    types::{
+        prelude::*, serialize_address, LivenessServiceProvider, Platform, TxOrdererAction,
+        TxOrdererHistoryEntry,
+    },
+};
+
+/// Address-keyed record written before the registry became cluster-scoped.
+#[derive(Clone, Debug, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address))]
+pub struct TxOrdererRpcInfo {
+    #[serde(serialize_with = "serialize_address")]
+    tx_orderer_address: Address,
+    external_rpc_url: String,
+    cluster_rpc_url: String,
+}
+
+impl TxOrdererRpcInfo {
+    /// The record in the current layout, as served until it is migrated. The
+    /// cluster is not known before then, so it is left empty.
+    pub fn to_unmigrated(&self) -> crate::types::TxOrdererRpcInfo {
+        crate::types::TxOrdererRpcInfo::migrated(
+            Platform::Ethereum,
+            LivenessServiceProvider::Radius,
+            String::new(),
+            self.tx_orderer_address.clone(),
+            self.external_rpc_url.clone(),
+            self.cluster_rpc_url.clone(),
+            0,
+        )
+    }
+
+    /// Move the records of the given cluster members from the address-keyed
+    /// layout to the cluster-scoped one, so that they are indexed and seen by
+    /// the background tasks, and return the migrated addresses.
+    ///
+    /// The legacy layout did not keep the cluster, so a record moves to the
+    /// first cluster it is found in. Registration only ever succeeded on
+    /// Ethereum with the Radius liveness service provider, so clusters of any
+    /// other pair are skipped.
+    pub fn migrate_cluster(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+        block_number: u64,
+        tx_orderer_address_list: impl IntoIterator<Item = Address>,
+    ) -> Result<Vec<Address>, Error> {
+        if platform != Platform::Ethereum
+            || liveness_service_provider != LivenessServiceProvider::Radius
+        {
+            return Ok(Vec::new());
+        }
+
+        let mut migrated = Vec::new();
+        for tx_orderer_address in tx_orderer_address_list {
+            let Ok(legacy_tx_orderer_rpc_info) = Self::get(&tx_orderer_address) else {
+                continue;
+            };
+
+            tracing::info!(
+                "Migrate legacy tx_orderer - cluster_id: {:?}, address: {:?}",
+                cluster_id,
+                tx_orderer_address.as_hex_string()
+            );
+
+            let tx_orderer_rpc_info = crate::types::TxOrdererRpcInfo::migrated(
+                platform,
+                liveness_service_provider,
+                cluster_id.to_owned(),
+                legacy_tx_orderer_rpc_info.tx_orderer_address,
+                legacy_tx_orderer_rpc_info.external_rpc_url,
+                legacy_tx_orderer_rpc_info.cluster_rpc_url,
+                block_number,
+            );
+            // Also deletes the legacy record.
+            tx_orderer_rpc_info.register()?;
+
+            TxOrdererHistoryEntry::from_rpc_info(TxOrdererAction::Migrate, &tx_orderer_rpc_info)
+                .with_block_number(block_number)
+                .append(&tx_orderer_address)?;
+
+            migrated.push(tx_orderer_address);
+        }
+
+        Ok(migrated)
+    }
+}
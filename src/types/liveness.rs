@@ -1,18 +1,44 @@
 use std::{
-    collections::btree_set::{BTreeSet, Iter},
+    collections::{
+        btree_set::{BTreeSet, Iter},
+        BTreeMap, VecDeque,
+    },
+    path::PathBuf,
     str::FromStr,
 };
 
 use crate::{
     error::Error,
-    types::prelude::{ChainType, Deserialize, Model, Serialize},
+    types::{
+        prelude::{Address, ChainType, Deserialize, Model, Serialize},
+        TxOrdererRpcInfo,
+    },
 };
 
+/// Number of processed blocks kept to roll back registry changes on a reorg.
+pub const LIVENESS_JOURNAL_LENGTH: usize = 128;
+
+/// Block time of Ethereum mainnet in seconds.
+pub const DEFAULT_BLOCK_TIME: u64 = 12;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum Platform {
     Ethereum,
     Local,
+    /// Any EVM chain, such as an L2 or a testnet, identified by its chain ID.
+    Evm(u64),
+}
+
+impl Platform {
+    /// The chain ID the liveness RPC endpoints must report, if the platform
+    /// pins one.
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Self::Evm(chain_id) => Some(*chain_id),
+            Self::Ethereum | Self::Local => None,
+        }
+    }
 }
 
 impl From<Platform> for ChainType {
@@ -20,6 +46,7 @@ impl From<Platform> for ChainType {
         match value {
             Platform::Ethereum => ChainType::Ethereum,
             Platform::Local => ChainType::Ethereum,
+            Platform::Evm(_) => ChainType::Ethereum,
         }
     }
 }
@@ -27,11 +54,18 @@ impl From<Platform> for ChainType {
 impl FromStr for Platform {
     type Err = Error;
 
+    /// Accepts "ethereum", "local" and "evm:<chain_id>".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "ethereum" | "Ethereum" => Ok(Self::Ethereum),
             "local" | "Local" => Ok(Self::Local),
-            _ => Err(Error::UnsupportedPlatform),
+            _ => match s.split_once(':') {
+                Some(("evm" | "Evm", chain_id)) => chain_id
+                    .parse()
+                    .map(Self::Evm)
+                    .map_err(|_| Error::UnsupportedPlatform),
+                _ => Err(Error::UnsupportedPlatform),
+            },
         }
     }
 }
@@ -45,6 +79,7 @@ pub enum LivenessServiceProvider {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ValidationServiceProvider {
+    #[serde(alias = "eigenlayer")]
     EigenLayer,
     Symbiotic,
 }
@@ -56,7 +91,7 @@ impl FromStr for ValidationServiceProvider {
         match s.to_lowercase().as_str() {
             "eigen_layer" | "eigenlayer" => Ok(Self::EigenLayer),
             "symbiotic" => Ok(Self::Symbiotic),
-            _ => Ok(Self::Symbiotic), // Using default value // TODO: error handling
+            _ => Err(Error::UnsupportedValidationServiceProvider(s.to_owned())),
         }
     }
 }
@@ -69,15 +104,149 @@ pub enum LivenessInfoPayload {
     Local(LivenessLocal),
 }
 
+impl LivenessInfoPayload {
+    /// Check that the payload is meant for the platform. The client is picked
+    /// by the payload, so a local allow-list must not stand in for the
+    /// contract of a chain.
+    pub fn check_platform(&self, platform: Platform) -> Result<(), Error> {
+        match (self, platform) {
+            (Self::Ethereum(_), Platform::Ethereum | Platform::Evm(_))
+            | (Self::Local(_), Platform::Local) => Ok(()),
+            _ => Err(Error::LivenessInfoPayloadMismatch(platform)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LivenessRadius {
     pub liveness_rpc_url: String,
     pub liveness_websocket_url: String,
     pub contract_address: String,
+    /// Additional RPC endpoints to fail over to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub liveness_rpc_url_list: Vec<String>,
+    /// Additional websocket endpoints to fail over to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub liveness_websocket_url_list: Vec<String>,
+    /// Number of RPC endpoints that must agree on the block number and the
+    /// cluster membership. Without it, the active endpoint is trusted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quorum: Option<usize>,
+    /// Storage slot of the `cluster_id => tx_orderer list` mapping in the
+    /// liveness contract. When set, cluster membership is read with
+    /// `eth_getProof` and verified against the state root of the block
+    /// instead of trusting the result of `eth_call`. Requires a quorum of at
+    /// least 2 to take the block header from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_orderer_list_slot: Option<u64>,
+    /// Average block time of the liveness chain in seconds, which sets how
+    /// long contract queries are cached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<u64>,
+    /// When set, registration also requires the tx_orderer to be an active
+    /// operator of the validation service provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_info: Option<ValidationInfo>,
+}
+
+impl LivenessRadius {
+    pub fn block_time(&self) -> u64 {
+        self.block_time.unwrap_or(DEFAULT_BLOCK_TIME)
+    }
+
+    pub fn rpc_urls(&self) -> Vec<String> {
+        merge_urls(&self.liveness_rpc_url, &self.liveness_rpc_url_list)
+    }
+
+    pub fn websocket_urls(&self) -> Vec<String> {
+        merge_urls(
+            &self.liveness_websocket_url,
+            &self.liveness_websocket_url_list,
+        )
+    }
+}
+
+fn merge_urls(primary_url: &str, url_list: &[String]) -> Vec<String> {
+    let mut urls = vec![primary_url.to_owned()];
+    for url in url_list {
+        if !urls.contains(url) {
+            urls.push(url.clone());
+        }
+    }
+
+    urls
+}
+
+/// Contracts of the validation service provider the tx_orderers of a liveness
+/// entry must be operators of. The contracts live on the liveness chain.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ValidationInfo {
+    pub validation_service_provider: ValidationServiceProvider,
+    /// EigenLayer `DelegationManager` or Symbiotic `OperatorRegistry`.
+    pub operator_registry_address: String,
+    /// EigenLayer `AVSDirectory` or Symbiotic operator-network `OptInService`.
+    pub opt_in_service_address: String,
+    /// The AVS on EigenLayer or the network on Symbiotic.
+    pub network_address: String,
+    /// Strategies on EigenLayer or vault delegators on Symbiotic whose stake
+    /// counts towards `minimum_stake`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stake_address_list: Vec<String>,
+    /// Identifier of the Symbiotic subnetwork stake is read for.
+    #[serde(default)]
+    pub subnetwork_identifier: u64,
+    /// Minimum total stake in the smallest unit. The stake is not checked when
+    /// it is unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_stake: Option<u128>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QueryCacheStats {
+    pub hit_count: u64,
+    pub miss_count: u64,
+    pub tx_orderer_list_count: usize,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EndpointHealth {
+    pub url: String,
+    pub is_active: bool,
+    pub success_count: u64,
+    pub failure_count: u64,
+    pub consecutive_failures: u64,
+    pub last_error: Option<String>,
+    pub last_failure_at: Option<u64>,
+    /// Number of answers that differed from the quorum.
+    pub disagreement_count: u64,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct LivenessLocal(serde_json::Value);
+#[serde(rename_all = "snake_case", tag = "state")]
+pub enum LivenessClientStatus {
+    Initializing {
+        attempt: u32,
+        last_error: Option<String>,
+    },
+    Ready,
+    /// Initialization keeps failing. It is still retried.
+    Failed {
+        attempt: u32,
+        reason: String,
+    },
+}
+
+/// Cluster membership for local ordering, given either inline or as a path to
+/// a JSON file with the same `cluster_id -> [tx_orderer_address]` map. The file
+/// is read on every lookup so that it can be edited without restarting.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LivenessLocal {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cluster_list: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cluster_list_path: Option<PathBuf>,
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
 #[kvstore(key())]
@@ -100,7 +269,142 @@ impl LivenessInfoList {
         self.0.remove(&(platform, liveness_service_provider));
     }
 
+    pub fn contains(
+        &self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+    ) -> bool {
+        self.0.contains(&(platform, liveness_service_provider))
+    }
+
     pub fn iter(&self) -> Iter<'_, (Platform, LivenessServiceProvider)> {
         self.0.iter()
     }
 }
+
+/// The last liveness block the seeder applied to the registry, together with a
+/// journal of the registry changes made at recent finalized blocks.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(platform: Platform, liveness_service_provider: LivenessServiceProvider))]
+pub struct LivenessCheckpoint {
+    journal: VecDeque<ProcessedBlock>,
+    /// The latest block processed, which the backfill resumes from.
+    #[serde(default)]
+    latest_block: Option<LatestBlock>,
+    /// Cluster membership changes of registered tx_orderers seen in contract
+    /// events, applied until the block they were made at is finalized.
+    #[serde(default)]
+    pending_change_list: Vec<MembershipChange>,
+    /// Every cluster is diffed against the contract until this block is
+    /// finalized, for contract logs found by the backfill.
+    #[serde(default)]
+    sweep_until: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipChange {
+    pub cluster_id: String,
+    pub tx_orderer_address: Address,
+    pub block_number: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LatestBlock {
+    pub block_number: u64,
+    pub block_hash: String,
+    /// The block the finality policy selected when the block was processed.
+    pub finalized_block_number: u64,
+}
+
+/// A finalized block and the registry entries removed because of the cluster
+/// membership at it. The entries are restored only if this block, rather than
+/// the latest block at the time, is reorganized.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProcessedBlock {
+    pub block_number: u64,
+    pub block_hash: String,
+    /// Registry entries removed while processing the block.
+    pub removed: Vec<TxOrdererRpcInfo>,
+}
+
+impl LivenessCheckpoint {
+    pub fn block_number(&self) -> Option<u64> {
+        self.latest_block
+            .as_ref()
+            .map(|latest_block| latest_block.block_number)
+    }
+
+    pub fn latest_block(&self) -> Option<&LatestBlock> {
+        self.latest_block.as_ref()
+    }
+
+    pub fn set_latest_block(&mut self, latest_block: LatestBlock) {
+        self.latest_block = Some(latest_block);
+    }
+
+    pub fn pending_change_list(&self) -> &Vec<MembershipChange> {
+        &self.pending_change_list
+    }
+
+    pub fn track(&mut self, change: MembershipChange) {
+        match self.pending_change_list.iter_mut().find(|pending_change| {
+            pending_change.cluster_id == change.cluster_id
+                && pending_change.tx_orderer_address == change.tx_orderer_address
+        }) {
+            Some(pending_change) => {
+                pending_change.block_number = pending_change.block_number.max(change.block_number)
+            }
+            None => self.pending_change_list.push(change),
+        }
+    }
+
+    pub fn sweep_until(&self) -> Option<u64> {
+        self.sweep_until
+    }
+
+    pub fn set_sweep_until(&mut self, block_number: u64) {
+        self.sweep_until = Some(self.sweep_until.unwrap_or_default().max(block_number));
+    }
+
+    /// Drop the pending changes and the sweep that are settled at the
+    /// finalized block.
+    pub fn settle(&mut self, finalized_block_number: u64) {
+        self.pending_change_list
+            .retain(|pending_change| pending_change.block_number > finalized_block_number);
+
+        if self
+            .sweep_until
+            .is_some_and(|sweep_until| sweep_until <= finalized_block_number)
+        {
+            self.sweep_until = None;
+        }
+    }
+
+    /// The most recent finalized block with registry changes.
+    pub fn latest(&self) -> Option<&ProcessedBlock> {
+        self.journal.back()
+    }
+
+    /// Journal the entries removed at the finalized block, adding to the
+    /// journal entry of the block if it already has one.
+    pub fn push(&mut self, block: ProcessedBlock) {
+        if block.removed.is_empty() {
+            return;
+        }
+
+        match self.journal.back_mut() {
+            Some(latest) if latest.block_hash == block.block_hash => {
+                latest.removed.extend(block.removed);
+            }
+            _ => self.journal.push_back(block),
+        }
+
+        while self.journal.len() > LIVENESS_JOURNAL_LENGTH {
+            self.journal.pop_front();
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<ProcessedBlock> {
+        self.journal.pop_back()
+    }
+}
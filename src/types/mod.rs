@@ -1,6 +1,11 @@
 mod config;
 mod executor;
+mod history;
+pub mod legacy;
 mod liveness;
+mod nonce;
+mod registry_change;
+mod signature_domain;
 mod tx_orderer;
 mod prelude {
     pub use radius_sdk::{
@@ -12,5 +17,9 @@ mod prelude {
 
 pub use config::*;
 pub use executor::*;
+pub use history::*;
 pub use liveness::*;
+pub use nonce::*;
+pub use registry_change::*;
+pub use signature_domain::*;
 pub use tx_orderer::*;
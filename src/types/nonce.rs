@@ -0,0 +1,30 @@
+use crate::{error::Error, types::prelude::*};
+
+/// The last nonce accepted from a signer. A signed message whose nonce is
+/// not greater than the stored one is treated as a replay and rejected.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address))]
+pub struct Nonce(u64);
+
+impl Nonce {
+    pub fn check(address: &Address, nonce: u64) -> Result<(), Error> {
+        let last_nonce = Self::get_or(address, Self::default)?;
+        if nonce <= last_nonce.0 {
+            return Err(Error::StaleNonce);
+        }
+
+        Ok(())
+    }
+
+    pub fn commit(address: &Address, nonce: u64) -> Result<(), Error> {
+        let mut last_nonce = Self::get_mut_or(address, Self::default)?;
+        if nonce <= last_nonce.0 {
+            return Err(Error::StaleNonce);
+        }
+
+        last_nonce.0 = nonce;
+        last_nonce.update()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    error::Error,
+    types::{prelude::*, serialize_address, LivenessServiceProvider, Platform, TxOrdererRpcInfo},
+    util::current_timestamp,
+};
+
+/// Number of changes kept in the feed. Clients that fall further behind have
+/// to reload the registry with `get_tx_orderer_rpc_info_list`.
+pub const REGISTRY_CHANGE_RETENTION: u64 = 100_000;
+
+/// Sequence number of the latest registry change. Sequence numbers start at 1
+/// and never go back, so a client can resume from the last one it applied.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key())]
+pub struct RegistrySequence(u64);
+
+impl RegistrySequence {
+    pub fn latest(&self) -> u64 {
+        self.0
+    }
+
+    /// Oldest sequence number still kept in the feed.
+    pub fn oldest(&self) -> u64 {
+        self.0.saturating_sub(REGISTRY_CHANGE_RETENTION) + 1
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryDiff {
+    /// The record was registered or changed, and is now `tx_orderer_rpc_info`.
+    Upsert {
+        tx_orderer_rpc_info: TxOrdererRpcInfo,
+    },
+    /// The record under the key was removed.
+    Remove {
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: String,
+        #[serde(serialize_with = "serialize_address")]
+        tx_orderer_address: Address,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Model)]
+#[kvstore(key(seq: u64))]
+pub struct RegistryChange {
+    pub seq: u64,
+    pub timestamp: u64,
+    pub diff: RegistryDiff,
+}
+
+impl RegistryChange {
+    pub fn upsert(tx_orderer_rpc_info: &TxOrdererRpcInfo) -> Result<u64, Error> {
+        Self::append(RegistryDiff::Upsert {
+            tx_orderer_rpc_info: tx_orderer_rpc_info.clone(),
+        })
+    }
+
+    pub fn remove(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+        tx_orderer_address: &Address,
+    ) -> Result<u64, Error> {
+        Self::append(RegistryDiff::Remove {
+            platform,
+            liveness_service_provider,
+            cluster_id: cluster_id.to_owned(),
+            tx_orderer_address: tx_orderer_address.clone(),
+        })
+    }
+
+    /// Store the diff under the next sequence number and drop the change that
+    /// fell out of the retention window.
+    fn append(diff: RegistryDiff) -> Result<u64, Error> {
+        let mut sequence = RegistrySequence::get_mut_or(RegistrySequence::default)?;
+        let seq = sequence.0 + 1;
+
+        let change = Self {
+            seq,
+            timestamp: current_timestamp(),
+            diff,
+        };
+        Self::put(&change, seq)?;
+
+        if seq > REGISTRY_CHANGE_RETENTION {
+            Self::delete(seq - REGISTRY_CHANGE_RETENTION)?;
+        }
+
+        sequence.0 = seq;
+        sequence.update()?;
+
+        Ok(seq)
+    }
+
+    /// Up to `limit` changes after `since_seq`, in order. Fails when changes
+    /// after `since_seq` are no longer kept.
+    pub fn get_since(since_seq: u64, limit: u64) -> Result<(Vec<Self>, u64), Error> {
+        let sequence = RegistrySequence::get_or(RegistrySequence::default)?;
+        if since_seq >= sequence.latest() {
+            return Ok((Vec::new(), sequence.latest()));
+        }
+
+        if since_seq + 1 < sequence.oldest() {
+            return Err(Error::RegistryChangesPruned(sequence.oldest()));
+        }
+
+        let end = since_seq.saturating_add(limit).min(sequence.latest());
+        let change_list = (since_seq + 1..=end)
+            .map(|seq| Self::get(seq).map_err(Error::from))
+            .collect::<Result<Vec<Self>, Error>>()?;
+
+        Ok((change_list, sequence.latest()))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::types::{prelude::*, Platform};
+
+pub const SIGNATURE_DOMAIN_NAME: &str = "radius_seeder";
+
+/// Binds a signed message to a single seeder and platform so that a signature
+/// made for one of them cannot be replayed against another.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignatureDomain {
+    name: String,
+    platform: Platform,
+    seeder_address: String,
+}
+
+#[derive(Serialize)]
+pub struct DomainMessage<'a, T> {
+    domain: &'a SignatureDomain,
+    method: &'a str,
+    message: &'a T,
+}
+
+impl SignatureDomain {
+    pub fn new(platform: Platform, seeder_address: &Address) -> Self {
+        Self {
+            name: SIGNATURE_DOMAIN_NAME.to_owned(),
+            platform,
+            seeder_address: seeder_address.as_hex_string(),
+        }
+    }
+
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    pub fn seeder_address(&self) -> &String {
+        &self.seeder_address
+    }
+
+    /// Wrap the message so that the domain and the RPC method become part of
+    /// the signed payload. Messages of different methods can share the same
+    /// fields, so without the method a signature for one would be accepted
+    /// by the other.
+    pub fn wrap<'a, T>(&'a self, method: &'a str, message: &'a T) -> DomainMessage<'a, T>
+    where
+        T: Serialize,
+    {
+        DomainMessage {
+            domain: self,
+            method,
+            message,
+        }
+    }
+}
@@ -1,4 +1,13 @@
-use crate::types::prelude::*;
+use std::collections::btree_set::{BTreeSet, Iter};
+
+use crate::{
+    error::Error,
+    types::{legacy, prelude::*, LivenessServiceProvider, Platform, RegistryChange},
+    util::current_timestamp,
+};
+
+/// Number of previous RPC URLs kept on a record.
+pub const RPC_URL_HISTORY_LENGTH: usize = 16;
 
 pub fn serialize_address<S>(address: &Address, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -8,27 +17,125 @@ where
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Model)]
-#[kvstore(key(address: &Address))]
+#[kvstore(key(platform: Platform, liveness_service_provider: LivenessServiceProvider, cluster_id: &str, address: &Address))]
 pub struct TxOrdererRpcInfo {
+    platform: Platform,
+    liveness_service_provider: LivenessServiceProvider,
+    cluster_id: String,
     #[serde(serialize_with = "serialize_address")]
     tx_orderer_address: Address,
     external_rpc_url: String,
     cluster_rpc_url: String,
+    /// Liveness block the cluster membership was checked at on registration.
+    #[serde(default)]
+    admission_block_number: u64,
+    /// Timestamp after which the registration expires unless it is renewed.
+    /// Records written before leases existed have none until the lease
+    /// sweeper assigns one.
+    #[serde(default)]
+    lease_expires_at: Option<u64>,
+    /// RPC URLs replaced through `update_tx_orderer_rpc_url`, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rpc_url_history: Vec<RpcUrlChange>,
+    #[serde(default)]
+    registered_at: u64,
+    /// Timestamp of the last successful health check.
+    #[serde(default)]
+    last_seen_at: Option<u64>,
+    /// Timestamp of the first failed health check since the last successful
+    /// one.
+    #[serde(default)]
+    unhealthy_since: Option<u64>,
+    #[serde(default)]
+    status: TxOrdererStatus,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxOrdererStatus {
+    #[default]
+    Active,
+    /// The last health check failed.
+    Unhealthy,
+    /// The tx_orderer left its cluster on chain, which takes effect once the
+    /// change reaches the finalized block.
+    Draining,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RpcUrlChange {
+    pub external_rpc_url: String,
+    pub cluster_rpc_url: String,
+    pub replaced_at: u64,
 }
 
 impl TxOrdererRpcInfo {
     pub fn new(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: String,
         tx_orderer_address: Address,
         external_rpc_url: String,
         cluster_rpc_url: String,
+        admission_block_number: u64,
     ) -> Self {
+        let now = current_timestamp();
+
         Self {
+            platform,
+            liveness_service_provider,
+            cluster_id,
             tx_orderer_address,
             external_rpc_url,
             cluster_rpc_url,
+            admission_block_number,
+            lease_expires_at: None,
+            rpc_url_history: Vec::new(),
+            registered_at: now,
+            last_seen_at: Some(now),
+            unhealthy_since: None,
+            status: TxOrdererStatus::Active,
         }
     }
 
+    /// Record moved from the address-keyed layout of older versions, which
+    /// did not keep when the tx_orderer registered or was last seen.
+    pub fn migrated(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: String,
+        tx_orderer_address: Address,
+        external_rpc_url: String,
+        cluster_rpc_url: String,
+        admission_block_number: u64,
+    ) -> Self {
+        Self {
+            registered_at: 0,
+            last_seen_at: None,
+            ..Self::new(
+                platform,
+                liveness_service_provider,
+                cluster_id,
+                tx_orderer_address,
+                external_rpc_url,
+                cluster_rpc_url,
+                admission_block_number,
+            )
+        }
+    }
+
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    pub fn liveness_service_provider(&self) -> LivenessServiceProvider {
+        self.liveness_service_provider
+    }
+
+    pub fn cluster_id(&self) -> &String {
+        &self.cluster_id
+    }
+
     pub fn tx_orderer_address(&self) -> &Address {
         &self.tx_orderer_address
     }
@@ -40,4 +147,309 @@ impl TxOrdererRpcInfo {
     pub fn cluster_rpc_url(&self) -> &String {
         &self.cluster_rpc_url
     }
+
+    pub fn admission_block_number(&self) -> u64 {
+        self.admission_block_number
+    }
+
+    pub fn lease_expires_at(&self) -> Option<u64> {
+        self.lease_expires_at
+    }
+
+    pub fn set_lease_expires_at(&mut self, lease_expires_at: u64) {
+        self.lease_expires_at = Some(lease_expires_at);
+    }
+
+    pub fn registered_at(&self) -> u64 {
+        self.registered_at
+    }
+
+    pub fn last_seen_at(&self) -> Option<u64> {
+        self.last_seen_at
+    }
+
+    pub fn status(&self) -> TxOrdererStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: TxOrdererStatus) {
+        self.status = status;
+    }
+
+    /// Record the outcome of a health check. A draining tx_orderer stays
+    /// draining until it rejoins its cluster on chain.
+    pub fn record_health_check(&mut self, is_healthy: bool, timestamp: u64) {
+        if is_healthy {
+            self.last_seen_at = Some(timestamp);
+            self.unhealthy_since = None;
+        } else if self.unhealthy_since.is_none() {
+            self.unhealthy_since = Some(timestamp);
+        }
+
+        if self.status != TxOrdererStatus::Draining {
+            self.status = match is_healthy {
+                true => TxOrdererStatus::Active,
+                false => TxOrdererStatus::Unhealthy,
+            };
+        }
+    }
+
+    /// Whether the tx_orderer is unhealthy and has not been seen for longer
+    /// than the grace period. A tx_orderer that was never seen counts from
+    /// its first failed health check.
+    pub fn is_expired(&self, now: u64, eviction_grace_period: u64) -> bool {
+        let Some(unhealthy_since) = self.unhealthy_since else {
+            return false;
+        };
+
+        let last_seen_at = self.last_seen_at.unwrap_or(unhealthy_since);
+        now.saturating_sub(last_seen_at) > eviction_grace_period
+    }
+
+    pub fn rpc_url_history(&self) -> &Vec<RpcUrlChange> {
+        &self.rpc_url_history
+    }
+
+    /// Replace the RPC URLs, keeping the previous ones in the history.
+    pub fn set_rpc_url(&mut self, external_rpc_url: String, cluster_rpc_url: String) {
+        let previous_external_rpc_url =
+            std::mem::replace(&mut self.external_rpc_url, external_rpc_url);
+        let previous_cluster_rpc_url =
+            std::mem::replace(&mut self.cluster_rpc_url, cluster_rpc_url);
+
+        self.rpc_url_history.push(RpcUrlChange {
+            external_rpc_url: previous_external_rpc_url,
+            cluster_rpc_url: previous_cluster_rpc_url,
+            replaced_at: current_timestamp(),
+        });
+
+        if self.rpc_url_history.len() > RPC_URL_HISTORY_LENGTH {
+            self.rpc_url_history.remove(0);
+        }
+    }
+
+    /// Store the record and index its cluster under the tx_orderer address.
+    pub fn register(&self) -> Result<(), Error> {
+        Self::put(
+            self,
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            &self.tx_orderer_address,
+        )?;
+
+        let mut cluster_list = TxOrdererClusterList::get_mut_or(
+            &self.tx_orderer_address,
+            TxOrdererClusterList::default,
+        )?;
+        cluster_list.insert(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+        );
+        cluster_list.update()?;
+
+        let mut tx_orderer_list = ClusterTxOrdererList::get_mut_or(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+        tx_orderer_list.insert(&self.tx_orderer_address);
+        tx_orderer_list.update()?;
+
+        let mut cluster_id_list = ClusterIdList::get_mut_or(ClusterIdList::default)?;
+        cluster_id_list.insert(
+            self.platform,
+            self.liveness_service_provider,
+            &self.cluster_id,
+        );
+        cluster_id_list.update()?;
+
+        // The record now lives under the cluster-scoped key.
+        legacy::TxOrdererRpcInfo::delete(&self.tx_orderer_address)?;
+
+        RegistryChange::upsert(self)?;
+
+        Ok(())
+    }
+
+    /// Remove the record of the cluster and drop the cluster from the address
+    /// index.
+    pub fn deregister(
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+        address: &Address,
+    ) -> Result<(), Error> {
+        Self::delete(platform, liveness_service_provider, cluster_id, address)?;
+
+        let mut cluster_list =
+            TxOrdererClusterList::get_mut_or(address, TxOrdererClusterList::default)?;
+        cluster_list.remove(platform, liveness_service_provider, cluster_id);
+        cluster_list.update()?;
+
+        let mut tx_orderer_list = ClusterTxOrdererList::get_mut_or(
+            platform,
+            liveness_service_provider,
+            cluster_id,
+            ClusterTxOrdererList::default,
+        )?;
+        tx_orderer_list.remove(address);
+        tx_orderer_list.update()?;
+
+        if tx_orderer_list.is_empty() {
+            let mut cluster_id_list = ClusterIdList::get_mut_or(ClusterIdList::default)?;
+            cluster_id_list.remove(platform, liveness_service_provider, cluster_id);
+            cluster_id_list.update()?;
+        }
+
+        legacy::TxOrdererRpcInfo::delete(address)?;
+
+        RegistryChange::remove(platform, liveness_service_provider, cluster_id, address)?;
+
+        Ok(())
+    }
+
+    /// Get the record of the tx_orderer regardless of the cluster it belongs
+    /// to. Entries in the address-keyed layout of older versions are served
+    /// as they are, without a cluster, until they are migrated.
+    pub fn get_by_address(address: &Address) -> Result<Self, Error> {
+        let cluster_list = TxOrdererClusterList::get_or(address, TxOrdererClusterList::default)?;
+        let Some((platform, liveness_service_provider, cluster_id)) = cluster_list.iter().next()
+        else {
+            return legacy::TxOrdererRpcInfo::get(address)
+                .map(|legacy_tx_orderer_rpc_info| legacy_tx_orderer_rpc_info.to_unmigrated())
+                .map_err(|_| Error::TxOrdererNotFound);
+        };
+
+        Ok(Self::get(
+            *platform,
+            *liveness_service_provider,
+            cluster_id,
+            address,
+        )?)
+    }
+}
+
+/// Clusters a tx_orderer has registered for, indexed by its address.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(address: &Address))]
+pub struct TxOrdererClusterList(BTreeSet<(Platform, LivenessServiceProvider, String)>);
+
+impl TxOrdererClusterList {
+    pub fn insert(
+        &mut self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+    ) {
+        self.0
+            .insert((platform, liveness_service_provider, cluster_id.to_owned()));
+    }
+
+    pub fn remove(
+        &mut self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+    ) {
+        self.0
+            .remove(&(platform, liveness_service_provider, cluster_id.to_owned()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, (Platform, LivenessServiceProvider, String)> {
+        self.0.iter()
+    }
+}
+
+/// Every cluster that has at least one registered tx_orderer.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key())]
+pub struct ClusterIdList(BTreeSet<(Platform, LivenessServiceProvider, String)>);
+
+impl ClusterIdList {
+    pub fn insert(
+        &mut self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+    ) {
+        self.0
+            .insert((platform, liveness_service_provider, cluster_id.to_owned()));
+    }
+
+    pub fn remove(
+        &mut self,
+        platform: Platform,
+        liveness_service_provider: LivenessServiceProvider,
+        cluster_id: &str,
+    ) {
+        self.0
+            .remove(&(platform, liveness_service_provider, cluster_id.to_owned()));
+    }
+
+    pub fn iter(&self) -> Iter<'_, (Platform, LivenessServiceProvider, String)> {
+        self.0.iter()
+    }
+}
+
+/// Tx_orderers registered for a cluster, indexed by the cluster.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key(platform: Platform, liveness_service_provider: LivenessServiceProvider, cluster_id: &str))]
+pub struct ClusterTxOrdererList(Vec<Address>);
+
+impl ClusterTxOrdererList {
+    pub fn insert(&mut self, address: &Address) {
+        if !self.contains(address) {
+            self.0.push(address.clone());
+        }
+    }
+
+    pub fn remove(&mut self, address: &Address) {
+        self.0.retain(|registered| registered != address);
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.0.iter().any(|registered| registered == address)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Address> {
+        self.0.iter()
+    }
+}
+
+/// Outcome of the last reconciliation between the registry and the cluster
+/// membership in the liveness contracts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Model)]
+#[kvstore(key())]
+pub struct ReconciliationReport {
+    pub reconciled_at: u64,
+    pub cluster_list: Vec<ClusterReconciliation>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClusterReconciliation {
+    pub platform: Platform,
+    pub liveness_service_provider: LivenessServiceProvider,
+    pub cluster_id: String,
+    pub block_number: Option<u64>,
+    /// Registered in the seeder but no longer in the cluster. These entries
+    /// are removed.
+    pub pruned: Vec<String>,
+    /// In the cluster with a record in the address-keyed layout of older
+    /// versions. These records are moved into the cluster.
+    #[serde(default)]
+    pub migrated: Vec<String>,
+    /// In the cluster but without RPC URLs in the seeder.
+    pub unregistered: Vec<String>,
+    pub error: Option<String>,
 }
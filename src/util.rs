@@ -1,14 +1,27 @@
-use std::{fs, io, path::Path, time::Duration};
+use std::{
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use reqwest::Client;
 
 use crate::error::Error;
 
-pub async fn health_check(tx_orderer_rpc_url: impl AsRef<str>) -> Result<(), Error> {
-    let health_check_url = format!("{}/health", tx_orderer_rpc_url.as_ref());
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub async fn health_check(rpc_url: impl AsRef<str>) -> Result<(), Error> {
+    health_check_with_timeout(rpc_url, HEALTH_CHECK_TIMEOUT).await
+}
+
+pub async fn health_check_with_timeout(
+    rpc_url: impl AsRef<str>,
+    timeout: Duration,
+) -> Result<(), Error> {
+    let health_check_url = format!("{}/health", rpc_url.as_ref());
 
     let client = Client::builder()
-        .timeout(Duration::from_secs(3))
+        .timeout(timeout)
         .build()
         .map_err(Error::InvalidURL)?;
 
@@ -21,6 +34,24 @@ pub async fn health_check(tx_orderer_rpc_url: impl AsRef<str>) -> Result<(), Err
     Ok(())
 }
 
+/// Lowercase hex without the `0x` prefix, so that addresses coming from the
+/// contract, the signature library and configuration files compare equal.
+pub fn normalize_address(address: impl AsRef<str>) -> String {
+    address
+        .as_ref()
+        .trim_start_matches("0x")
+        .trim_start_matches("0X")
+        .to_lowercase()
+}
+
+/// Seconds since the Unix epoch.
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 pub fn clear_dir<P: AsRef<Path>>(path: P) -> Result<(), io::Error> {
     if path.as_ref().exists() {
         for entry in fs::read_dir(&path)? {